@@ -5,7 +5,7 @@ pub mod scrollable;
 pub use scrollable::{Scrollable, Scrollbar};
 
 pub mod equal;
-pub use equal::{column::EqualWidthColumn, row::EqualHeightRow};
+pub use equal::{column::EqualWidthColumn, equal::Equal, row::EqualHeightRow};
 
 pub mod grid;
 pub use grid::Grid;
@@ -13,6 +13,17 @@ pub use grid::Grid;
 pub mod cell;
 pub use cell::cell::{Cell, cell};
 
+pub mod splitter;
+pub use splitter::{Splitter, splitter};
+
+pub mod density;
+pub use density::Density;
+
+pub mod axis;
+pub use axis::Axis;
+
+pub(crate) mod shared;
+
 //
 //
 // ----- iced widgets helper functions from `helper.rs`
@@ -0,0 +1,71 @@
+// This file is part of `iced_experimental` crate. For the terms of use, please see the file
+// called LICENSE-BSD-3-Clause at the top level of the `iced_experimental` crate.
+
+//! The [`Axis`] along which containers, such as the [`Splitter`] and the
+//! [`Equal`], place their children.
+
+#[doc(inline)]
+#[allow(unused_imports)]
+use crate::widget::{Equal, Splitter};
+
+use crate::core::{Point, Size, Vector, layout};
+
+/// The axis along which the children of a container are placed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Axis {
+    /// The children are placed side by side.
+    #[default]
+    Horizontal,
+    /// The children are placed above each other.
+    Vertical,
+}
+
+impl Axis {
+    /// The main axis component of the [`Size`].
+    pub(crate) fn main<T>(self, size: Size<T>) -> T {
+        match self {
+            Axis::Horizontal => size.width,
+            Axis::Vertical => size.height,
+        }
+    }
+
+    /// The cross axis component of the [`Size`].
+    pub(crate) fn cross<T>(self, size: Size<T>) -> T {
+        match self {
+            Axis::Horizontal => size.height,
+            Axis::Vertical => size.width,
+        }
+    }
+
+    /// The main axis component of the [`Point`].
+    pub(crate) fn main_point(self, point: Point) -> f32 {
+        match self {
+            Axis::Horizontal => point.x,
+            Axis::Vertical => point.y,
+        }
+    }
+
+    /// Builds a [`Size`] from the main and cross axis components.
+    pub(crate) fn size(self, main: f32, cross: f32) -> Size {
+        match self {
+            Axis::Horizontal => Size::new(main, cross),
+            Axis::Vertical => Size::new(cross, main),
+        }
+    }
+
+    /// Builds a [`Vector`] offset along the main axis.
+    pub(crate) fn offset(self, main: f32) -> Vector {
+        match self {
+            Axis::Horizontal => Vector::new(main, 0.0),
+            Axis::Vertical => Vector::new(0.0, main),
+        }
+    }
+
+    /// The flex layout axis of the [`Axis`].
+    pub(crate) fn flex(self) -> layout::flex::Axis {
+        match self {
+            Axis::Horizontal => layout::flex::Axis::Horizontal,
+            Axis::Vertical => layout::flex::Axis::Vertical,
+        }
+    }
+}
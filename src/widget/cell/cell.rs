@@ -7,6 +7,49 @@
 //! the content has changed. Also supports having alternative background when
 //! used in a grid of cells, such as alternative row background colouring.
 //!
+//! The style of the cell reacts to the cell being hovered, pressed, focused
+//! (the last clickable cell pressed), or disabled (clickable though without an
+//! `on_press` message, or explicitly with [`Cell::disabled()`]). Besides the
+//! ready-made [`style::Styling`] classes, any styling function may be used via
+//! [`Cell::style()`]. The text colour of the style is inherited by the content
+//! of the cell.
+//!
+//! The border may be limited to selected edges with [`Cell::sides()`], and the
+//! rounding to selected corners with [`Cell::corners()`], so a [`Grid`] of
+//! cells without spacing has single width lines between the cells, and only
+//! the outer corners of the table are rounded.
+//!
+//! For live updating content, [`Cell::flash()`] briefly highlights the cell
+//! whenever the content changes, fading back to the normal style.
+//!
+//! Content larger than the cell may be cut off with an ellipsis marker using
+//! [`Cell::ellipsis()`], and shown in full after hovering the cell using
+//! [`Cell::overflow_tooltip()`].
+//!
+//! A data bar may be drawn under the content with [`Cell::data_bar()`], such as
+//! for percentages, using the positive and negative bar colours of the theme.
+//!
+//! Cells support dragging and dropping between them, such as for moving items
+//! between two grids. A cell with [`Cell::drag_source()`] carries a payload
+//! of any type, which is delivered to the [`Cell::on_drop()`] message of the
+//! cell it is dropped on, if the payload is of the type expected by the cell.
+//!
+//! The result of validating the content may be set with [`Cell::validation()`],
+//! which styles the cell by the severity, and shows the message of a warning
+//! or an error below the cell while the cell or its content, such as a text
+//! input, is focused, or the cell is hovered.
+//!
+//! Small triangle [`Marker`]s may be drawn in the corners of the cell, such as
+//! for edited values or comments, independent of the change border colour.
+//! Hovering or clicking a marker shows the [`Cell::marker_content()`].
+//!
+//! The application may be notified of the pointer entering or leaving the
+//! cell, and of the pointer resting on the cell for a while, such as for
+//! highlighting related cells or showing a preview.
+//!
+//! The padding and the resize hover size default to those of the [`Density`]
+//! inherited from the enclosing container, such as a [`Grid`].
+//!
 //! # Example
 //! ```no_run
 //! # mod iced { pub mod widget { pub use iced_widget::*; } }
@@ -37,8 +80,13 @@
 use iced_widget::Text;
 */
 
+#[doc(inline)]
+#[allow(unused_imports)]
+use crate::widget::{Density, Grid};
+
 use super::style;
 use crate::core::{
+    Background,
     Border,
     Clipboard,
     Color,
@@ -53,13 +101,30 @@ use crate::core::{
     Size,
     Vector,
     alignment,
+    border::Radius,
     layout::{self, Layout},
     mouse,
     overlay,
     renderer,
+    time::{Duration, Instant},
     touch,
-    widget::{Operation, Tree, Widget, tree}, // operate = iced::runtime::widget
+    widget::{self, Operation, Tree, Widget, operation, tree}, // operate = iced::runtime::widget
+    window,
 };
+use crate::widget::density;
+use crate::widget::shared::{self, DRAG_THRESHOLD};
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+thread_local! {
+    /// The on-going drag of a [`Cell`], shared with all cells.
+    static DRAG: RefCell<Option<Drag>> = const { RefCell::new(None) };
+}
+
+/// The payload of a drag, and whether the drag has been released.
+struct Drag {
+    payload: Rc<dyn Any>,
+    is_released: bool,
+}
 
 /// Helper function for creating [`Cell`] instance.
 pub fn cell<'a, Message, Theme, Renderer>(
@@ -114,22 +179,47 @@ where
     on_resize_horizontal: Option<(Box<dyn Fn(f32) -> Message + 'a>, Message)>,
     #[allow(clippy::type_complexity)]
     on_resize_vertical: Option<(Box<dyn Fn(f32) -> Message + 'a>, Message)>,
+    on_resize_cancel: Option<Message>,
+    on_enter: Option<Message>,
+    on_exit: Option<Message>,
+    on_hover_intent: Option<(Message, Duration)>,
+    on_drag_enter: Option<Message>,
+    #[allow(clippy::type_complexity)]
+    on_drag_over: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    #[allow(clippy::type_complexity)]
+    on_drop: Option<Box<dyn Fn(&dyn Any) -> Option<Message> + 'a>>,
+    accepts: Option<fn(&dyn Any) -> bool>,
 
     // Layout
-    padding: Padding,
+    padding: Option<Padding>,
     height_resize_offset: Option<f32>,
     width_resize_offset: Option<f32>,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
-    resize_hover_size: f32,
+    resize_hover_size: Option<f32>,
 
     // Content
     clickable: bool,
+    disabled: bool,
     content: Element<'a, Message, Theme, Renderer>,
+    tooltip: Option<Element<'a, Message, Theme, Renderer>>,
+    tooltip_delay: Duration,
+    marker_content: Option<Element<'a, Message, Theme, Renderer>>,
+    drag_source: Option<Rc<dyn Any>>,
+    validation_message: Option<Element<'a, Message, Theme, Renderer>>,
 
     // Styling
-    styling: style::Styling,
+    class: Theme::Class<'a>,
+    changed: bool,
     change_color: Option<Color>,
+    sides: Sides,
+    corners: Corners,
+    flash: Option<(Flash, u64)>,
+    ellipsis: bool,
+    markers: Vec<Marker>,
+    data_bar: Option<(f32, BarDirection)>,
+    severity: Option<style::Severity>,
+    last_status: Option<style::Status>,
 }
 
 impl<'a, Message, Theme, Renderer> Cell<'a, Message, Theme, Renderer>
@@ -149,22 +239,45 @@ where
             on_press: None,
             on_resize_horizontal: None,
             on_resize_vertical: None,
+            on_resize_cancel: None,
+            on_enter: None,
+            on_exit: None,
+            on_hover_intent: None,
+            on_drag_enter: None,
+            on_drag_over: None,
+            on_drop: None,
+            accepts: None,
 
             // Layout
-            padding: Padding::new(2.0),
+            padding: None,
             height_resize_offset: None,
             width_resize_offset: None,
             align_x: alignment::Horizontal::Left,
             align_y: alignment::Vertical::Top,
-            resize_hover_size: 5.0,
+            resize_hover_size: None,
 
             // Content
             clickable,
+            disabled: false,
             content,
+            tooltip: None,
+            tooltip_delay: Duration::ZERO,
+            marker_content: None,
+            drag_source: None,
+            validation_message: None,
 
             // Styling
-            styling: style::Styling::ReadOnly,
+            class: Theme::default(),
+            changed: false,
             change_color: None,
+            sides: Sides::ALL,
+            corners: Corners::ALL,
+            flash: None,
+            ellipsis: false,
+            markers: Vec::new(),
+            data_bar: None,
+            severity: None,
+            last_status: None,
         }
     }
 
@@ -210,9 +323,87 @@ where
         self
     }
 
+    /// Sets the message that will be produced when an on-going resize is cancelled,
+    /// by the pointer leaving the window or the touch being lost, instead of the
+    /// `on_release` message. It is up to the consumer to discard the last `on_drag`
+    /// offset.
+    pub fn on_resize_cancel(mut self, message: Message) -> Self {
+        self.on_resize_cancel = Some(message);
+        self
+    }
+
+    /// Sets whether the [`Cell`] is disabled.
+    ///
+    /// A disabled [`Cell`] ignores presses and resizing, shows the default mouse
+    /// pointer, and is drawn with the [`style::Status::Disabled`] style. The
+    /// content itself still receives its events.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the message that will be produced when the pointer enters the [`Cell`].
+    pub fn on_enter(mut self, message: Message) -> Self {
+        self.on_enter = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the pointer leaves the [`Cell`].
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced once the pointer has rested on the
+    /// [`Cell`] without moving for the `delay`. Moving the pointer restarts the
+    /// delay, while the message is produced at most once until the pointer
+    /// leaves the [`Cell`].
+    pub fn on_hover_intent(mut self, message: Message, delay: Duration) -> Self {
+        self.on_hover_intent = Some((message, delay));
+        self
+    }
+
+    /// Makes the [`Cell`] a drag source carrying the `payload`.
+    ///
+    /// A drag starts once the pointer moves a few pixels while pressing the
+    /// [`Cell`], which then no longer produces the `on_press` message.
+    pub fn drag_source<P: 'static>(mut self, payload: P) -> Self {
+        self.drag_source = Some(Rc::new(payload));
+        self
+    }
+
+    /// Sets the message that will be produced when a drag with a payload accepted
+    /// by [`Cell::on_drop()`] enters the [`Cell`].
+    pub fn on_drag_enter(mut self, message: Message) -> Self {
+        self.on_drag_enter = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when a drag with a payload accepted
+    /// by [`Cell::on_drop()`] moves over the [`Cell`], with the position of the
+    /// pointer relative to the top left corner of the [`Cell`].
+    pub fn on_drag_over(mut self, on_drag_over: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_drag_over = Some(Box::new(on_drag_over));
+        self
+    }
+
+    /// Makes the [`Cell`] a drop target, producing the message when a drag with
+    /// a payload of type `P` is dropped on the [`Cell`].
+    ///
+    /// Drags with payloads of other types are ignored by the [`Cell`].
+    pub fn on_drop<P: Clone + 'static>(mut self, on_drop: impl Fn(P) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(move |payload: &dyn Any| {
+            payload.downcast_ref::<P>().cloned().map(&on_drop)
+        }));
+        self.accepts = Some(|payload| payload.is::<P>());
+        self
+    }
+
     /// Sets the [`Padding`] within the [`Cell`]. Must also include border width.
+    ///
+    /// Unless set, the cell padding of the inherited [`Density`] is used.
     pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
-        self.padding = padding.into();
+        self.padding = Some(padding.into());
         self
     }
 
@@ -241,14 +432,45 @@ where
     }
 
     /// Sets the resize detection space on either side of the widget's edge.
+    ///
+    /// Unless set, the resize hover size of the inherited [`Density`] is used.
     pub fn resize_hover_size(mut self, size: f32) -> Self {
-        self.resize_hover_size = size.min(1.0);
+        self.resize_hover_size = Some(size.max(1.0));
         self
     }
 
-    /// Sets the styling of the [`Cell`].
-    pub fn styling(mut self, styling: &style::Styling) -> Self {
-        self.styling = *styling;
+    /// Sets the styling of the [`Cell`] to one of the ready-made classes.
+    ///
+    /// Also marks the [`Cell`] as changed, if the ready-made class is for
+    /// changed content.
+    pub fn styling(mut self, styling: &style::Styling) -> Self
+    where
+        Theme::Class<'a>: From<style::Styling>,
+    {
+        self.class = (*styling).into();
+        self.changed = styling.is_changed();
+        self
+    }
+
+    /// Sets the style of the [`Cell`].
+    pub fn style(mut self, style: impl Fn(&Theme, style::Status) -> style::Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<style::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as style::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Cell`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets whether the content of the [`Cell`] has changed, which enables the
+    /// [`Cell::change_color()`].
+    pub fn changed(mut self, changed: bool) -> Self {
+        self.changed = changed;
         self
     }
 
@@ -257,12 +479,722 @@ where
         self.change_color = color;
         self
     }
+
+    /// Sets the edges of the [`Cell`] that have a border drawn.
+    pub fn sides(mut self, sides: Sides) -> Self {
+        self.sides = sides;
+        self
+    }
+
+    /// Sets the corners of the [`Cell`] that use the border radius of the style.
+    pub fn corners(mut self, corners: Corners) -> Self {
+        self.corners = corners;
+        self
+    }
+
+    /// Enables the [`Flash`] highlight of the [`Cell`].
+    ///
+    /// The `revision` identifies the content of the [`Cell`], such as a counter
+    /// or a hash of the value. Whenever the `revision` differs from the one of
+    /// the previous view, the [`Cell`] flashes.
+    pub fn flash(mut self, flash: Flash, revision: u64) -> Self {
+        self.flash = Some((flash, revision));
+        self
+    }
+
+    /// Sets whether content larger than the [`Cell`] is cut off at the padding,
+    /// with an ellipsis marker drawn at the bottom right of the content area.
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets the tooltip showing the full content, when the content is larger
+    /// than the [`Cell`] and the [`Cell`] has been hovered for the `delay`.
+    ///
+    /// The tooltip is placed below the [`Cell`], using the style of the
+    /// [`Cell`] for its background and border.
+    pub fn overflow_tooltip(
+        mut self,
+        tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+        delay: Duration,
+    ) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self.tooltip_delay = delay;
+        self
+    }
+
+    /// Sets the data bar of the [`Cell`], which fills the padded area under the
+    /// content in the given [`BarDirection`] in proportion to the `value`.
+    ///
+    /// The `value` is clamped to `-1.0..=1.0`, where the magnitude is the filled
+    /// fraction, and the sign selects the positive or negative bar colour.
+    pub fn data_bar(mut self, value: f32, direction: BarDirection) -> Self {
+        self.data_bar = Some((value.clamp(-1.0, 1.0), direction));
+        self
+    }
+
+    /// Sets the [`Validation`] result of the content of the [`Cell`].
+    ///
+    /// The style of the [`Cell`] is adjusted for the severity by the theme
+    /// catalog, while the message of a warning or an error is shown below the
+    /// [`Cell`] when the [`Cell`] or a widget of its content, such as a
+    /// `text_input`, is focused, or the [`Cell`] is hovered.
+    pub fn validation(mut self, validation: Validation<'a, Message, Theme, Renderer>) -> Self {
+        let (severity, message) = match validation {
+            Validation::Ok => (style::Severity::Ok, None),
+            Validation::Warning(message) => (style::Severity::Warning, Some(message)),
+            Validation::Error(message) => (style::Severity::Error, Some(message)),
+        };
+        self.severity = Some(severity);
+        self.validation_message = message;
+        self
+    }
+
+    /// Adds a corner [`Marker`] to the [`Cell`].
+    pub fn marker(mut self, marker: Marker) -> Self {
+        self.markers.push(marker);
+        self
+    }
+
+    /// Sets the content shown below the [`Cell`] while a [`Marker`] is hovered,
+    /// or after a [`Marker`] is clicked until pressing elsewhere, such as a
+    /// comment or the original value.
+    pub fn marker_content(
+        mut self,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.marker_content = Some(content.into());
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Cell<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    /// Determines the [`style::Status`] of the [`Cell`].
+    ///
+    /// Only clickable cells can be pressed or focused, while the hover status
+    /// is limited to clickable cells and resizing interactions.
+    fn status(&self, state: &State, is_hovered: bool) -> style::Status {
+        let is_resizing = state.drag_origin_horizontal.is_some()
+            || state.is_resize_hovered_horizontal
+            || state.drag_origin_vertical.is_some()
+            || state.is_resize_hovered_vertical;
+        if self.is_disabled() {
+            style::Status::Disabled
+        } else if state.is_drag_over {
+            style::Status::DropTarget
+        } else if self.clickable && state.is_pressed {
+            style::Status::Pressed
+        } else if self.clickable && state.is_focused {
+            style::Status::Focused { is_hovered }
+        } else if is_resizing || (self.clickable && is_hovered) {
+            style::Status::Hovered
+        } else {
+            style::Status::Active
+        }
+    }
+
+    /// Whether the [`Cell`] is disabled, either explicitly or by being clickable
+    /// without an `on_press` message.
+    fn is_disabled(&self) -> bool {
+        self.disabled || (self.clickable && self.on_press.is_none())
+    }
+
+    /// Draws the background and the borders of the selected edges.
+    fn draw_background(&self, renderer: &mut Renderer, bounds: Rectangle, style: &style::Style) {
+        let radius = Radius {
+            top_left: corner(self.corners.top_left, style.border.radius.top_left),
+            top_right: corner(self.corners.top_right, style.border.radius.top_right),
+            bottom_right: corner(self.corners.bottom_right, style.border.radius.bottom_right),
+            bottom_left: corner(self.corners.bottom_left, style.border.radius.bottom_left),
+        };
+        let edge = |side: bool, edge: Option<style::Edge>| {
+            if side {
+                edge.unwrap_or(style.border.into())
+            } else {
+                style::Edge::default()
+            }
+        };
+        let top = edge(self.sides.top, style.edges.top);
+        let right = edge(self.sides.right, style.edges.right);
+        let bottom = edge(self.sides.bottom, style.edges.bottom);
+        let left = edge(self.sides.left, style.edges.left);
+
+        // A uniform border is drawn by the quad itself.
+        if top == right && top == bottom && top == left {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        color: top.color,
+                        width: top.width,
+                        radius,
+                    },
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                style.background,
+            );
+            return;
+        }
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius,
+                    ..Border::default()
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            style.background,
+        );
+
+        // The corners are drawn first, using the top or bottom edge unless it is
+        // not drawn. A rounded corner is the rounded border of the whole cell
+        // clipped to the corner, so the edges follow the radius.
+        let corner_size = |vertical: style::Edge, horizontal: style::Edge, radius: f32| {
+            Size::new(vertical.width.max(radius), horizontal.width.max(radius))
+        };
+        let top_left = corner_size(left, top, radius.top_left);
+        let top_right = corner_size(right, top, radius.top_right);
+        let bottom_right = corner_size(right, bottom, radius.bottom_right);
+        let bottom_left = corner_size(left, bottom, radius.bottom_left);
+        let right_x = bounds.x + bounds.width;
+        let bottom_y = bounds.y + bounds.height;
+        let corners = [
+            (
+                Point::new(bounds.x, bounds.y),
+                top_left,
+                top,
+                left,
+                radius.top_left,
+            ),
+            (
+                Point::new(right_x - top_right.width, bounds.y),
+                top_right,
+                top,
+                right,
+                radius.top_right,
+            ),
+            (
+                Point::new(right_x - bottom_right.width, bottom_y - bottom_right.height),
+                bottom_right,
+                bottom,
+                right,
+                radius.bottom_right,
+            ),
+            (
+                Point::new(bounds.x, bottom_y - bottom_left.height),
+                bottom_left,
+                bottom,
+                left,
+                radius.bottom_left,
+            ),
+        ];
+        for (position, size, horizontal, vertical, corner_radius) in corners {
+            let edge = if horizontal.width > 0.0 {
+                horizontal
+            } else {
+                vertical
+            };
+            if edge.width <= 0.0 || size.width <= 0.0 || size.height <= 0.0 {
+                continue;
+            }
+            let corner_bounds = Rectangle::new(position, size);
+            if corner_radius > 0.0 {
+                renderer.with_layer(corner_bounds, |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: Border {
+                                color: edge.color,
+                                width: edge.width,
+                                radius,
+                            },
+                            shadow: Shadow::default(),
+                            snap: false,
+                        },
+                        Color::TRANSPARENT,
+                    );
+                });
+            } else {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: corner_bounds,
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    edge.color,
+                );
+            }
+        }
+
+        // The straight parts of the edges are drawn between the corners.
+        let edges = [
+            (
+                top,
+                Rectangle {
+                    x: bounds.x + top_left.width,
+                    y: bounds.y,
+                    width: bounds.width - top_left.width - top_right.width,
+                    height: top.width,
+                },
+            ),
+            (
+                right,
+                Rectangle {
+                    x: right_x - right.width,
+                    y: bounds.y + top_right.height,
+                    width: right.width,
+                    height: bounds.height - top_right.height - bottom_right.height,
+                },
+            ),
+            (
+                bottom,
+                Rectangle {
+                    x: bounds.x + bottom_left.width,
+                    y: bottom_y - bottom.width,
+                    width: bounds.width - bottom_left.width - bottom_right.width,
+                    height: bottom.width,
+                },
+            ),
+            (
+                left,
+                Rectangle {
+                    x: bounds.x,
+                    y: bounds.y + top_left.height,
+                    width: left.width,
+                    height: bounds.height - top_left.height - bottom_left.height,
+                },
+            ),
+        ];
+        for (edge, bounds) in edges {
+            if edge.width > 0.0 && bounds.width > 0.0 && bounds.height > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    edge.color,
+                );
+            }
+        }
+    }
+
+    /// The child widgets of the [`Cell`], in the order of the children of the
+    /// `Tree`.
+    fn elements(&self) -> Vec<&dyn Widget<Message, Theme, Renderer>> {
+        let mut elements = vec![self.content.as_widget()];
+        elements.extend(self.tooltip.as_ref().map(Element::as_widget));
+        elements.extend(self.marker_content.as_ref().map(Element::as_widget));
+        elements.extend(self.validation_message.as_ref().map(Element::as_widget));
+        elements
+    }
+
+    /// Draws the data bar within the padded bounds.
+    fn draw_data_bar(&self, renderer: &mut Renderer, bounds: Rectangle, bar: style::DataBar) {
+        let Some((value, direction)) = self.data_bar else {
+            return;
+        };
+        let fraction = value.abs();
+        let bounds = match direction {
+            BarDirection::LeftToRight => Rectangle {
+                width: bounds.width * fraction,
+                ..bounds
+            },
+            BarDirection::RightToLeft => Rectangle {
+                x: bounds.x + bounds.width * (1.0 - fraction),
+                width: bounds.width * fraction,
+                ..bounds
+            },
+            BarDirection::TopToBottom => Rectangle {
+                height: bounds.height * fraction,
+                ..bounds
+            },
+            BarDirection::BottomToTop => Rectangle {
+                y: bounds.y + bounds.height * (1.0 - fraction),
+                height: bounds.height * fraction,
+                ..bounds
+            },
+        };
+        if bounds.width > 0.0 && bounds.height > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                if value < 0.0 {
+                    bar.negative
+                } else {
+                    bar.positive
+                },
+            );
+        }
+    }
+
+    /// Draws the corner markers as staircase triangles.
+    fn draw_markers(&self, renderer: &mut Renderer, bounds: Rectangle) {
+        for marker in &self.markers {
+            let area = marker.bounds(bounds);
+            let steps = area.height.ceil().max(1.0) as usize;
+            let step = area.height / steps as f32;
+            for index in 0..steps {
+                // Each step narrows away from the corner.
+                let (y, width) = match marker.corner {
+                    Corner::TopLeft | Corner::TopRight => (
+                        area.y + index as f32 * step,
+                        area.width * (steps - index) as f32 / steps as f32,
+                    ),
+                    Corner::BottomRight | Corner::BottomLeft => (
+                        area.y + area.height - (index + 1) as f32 * step,
+                        area.width * (steps - index) as f32 / steps as f32,
+                    ),
+                };
+                let x = match marker.corner {
+                    Corner::TopLeft | Corner::BottomLeft => area.x,
+                    Corner::TopRight | Corner::BottomRight => area.x + area.width - width,
+                };
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x,
+                            y,
+                            width,
+                            height: step,
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    marker.color,
+                );
+            }
+        }
+    }
+
+    /// Draws the ellipsis marker of cut off content at the bottom right of the
+    /// content area, covering the content underneath with the background.
+    fn draw_ellipsis(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        style: &style::Style,
+        color: Color,
+    ) {
+        const DOT: f32 = 2.0;
+        const GAP: f32 = 2.0;
+        let width = (3.0 * DOT + 3.0 * GAP).min(bounds.width);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + bounds.width - width,
+                    width,
+                    ..bounds
+                },
+                border: Border::default(),
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            style.background,
+        );
+        // The dots start after a gap within the covered area.
+        let centre = Point::new(
+            bounds.x + bounds.width - width + GAP + (3.0 * DOT + 2.0 * GAP) / 2.0,
+            bounds.y + bounds.height - DOT / 2.0 - GAP,
+        );
+        shared::draw_ellipsis(renderer, centre, DOT, GAP, color);
+    }
+}
+
+/// The part of a [`Cell`] that is highlighted by a [`Flash`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FlashTarget {
+    /// The background of the [`Cell`] flashes.
+    #[default]
+    Background,
+    /// The border of the [`Cell`] flashes.
+    Border,
+}
+
+/// The highlight of a [`Cell`] when its content changes, which fades from the
+/// highlight [`Color`] back to the normal style over the duration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Flash {
+    /// The highlight [`Color`] at the start of the flash.
+    pub color: Color,
+    /// The duration of the fading back to the normal style.
+    pub duration: Duration,
+    /// The part of the [`Cell`] that flashes.
+    pub target: FlashTarget,
+}
+
+impl Flash {
+    /// Creates a [`Flash`] of the background, with the given highlight [`Color`]
+    /// and duration.
+    pub fn new(color: impl Into<Color>, duration: Duration) -> Self {
+        Self {
+            color: color.into(),
+            duration,
+            target: FlashTarget::Background,
+        }
+    }
+
+    /// Sets the [`FlashTarget`] of the [`Flash`].
+    pub fn target(mut self, target: FlashTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Applies the highlight to the [`style::Style`], where a `fade` of `1.0`
+    /// is the full highlight colour and `0.0` is the normal style.
+    fn apply(&self, style: style::Style, fade: f32) -> style::Style {
+        match self.target {
+            FlashTarget::Background => match style.background {
+                Background::Color(color) => style.background(style::mix(color, self.color, fade)),
+                _ => style,
+            },
+            FlashTarget::Border => {
+                let color = style::mix(style.border.color, self.color, fade);
+                let border = Border {
+                    color,
+                    ..style.border
+                };
+                style.border(border).edges(style.edges.color(color))
+            }
+        }
+    }
+}
+
+/// The result of validating the content of a [`Cell`], where a warning or an
+/// error has a message, such as a `Text`, shown below the [`Cell`].
+pub enum Validation<'a, Message, Theme, Renderer> {
+    /// The content is valid.
+    Ok,
+    /// The content is questionable.
+    Warning(Element<'a, Message, Theme, Renderer>),
+    /// The content is invalid.
+    Error(Element<'a, Message, Theme, Renderer>),
+}
+
+/// The direction the data bar of a [`Cell`] grows from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BarDirection {
+    /// The bar grows from the left edge.
+    #[default]
+    LeftToRight,
+    /// The bar grows from the right edge.
+    RightToLeft,
+    /// The bar grows from the top edge.
+    TopToBottom,
+    /// The bar grows from the bottom edge.
+    BottomToTop,
+}
+
+/// A corner of a [`Cell`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom right corner.
+    BottomRight,
+    /// The bottom left corner.
+    BottomLeft,
+}
+
+/// A small triangle drawn in a corner of a [`Cell`], such as to indicate the
+/// value has been edited or has a comment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marker {
+    /// The [`Corner`] of the marker.
+    pub corner: Corner,
+    /// The [`Color`] of the marker.
+    pub color: Color,
+    /// The length of the sides of the marker along the edges of the [`Cell`].
+    pub size: f32,
+}
+
+impl Marker {
+    /// Creates a [`Marker`] in the given [`Corner`] with the given [`Color`].
+    pub fn new(corner: Corner, color: impl Into<Color>) -> Self {
+        Self {
+            corner,
+            color: color.into(),
+            size: 6.0,
+        }
+    }
+
+    /// Sets the length of the sides of the [`Marker`].
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// The square area of the [`Marker`] within the bounds of the [`Cell`].
+    fn bounds(&self, bounds: Rectangle) -> Rectangle {
+        let size = self.size.min(bounds.width).min(bounds.height);
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => bounds.x,
+            Corner::TopRight | Corner::BottomRight => bounds.x + bounds.width - size,
+        };
+        let y = match self.corner {
+            Corner::TopLeft | Corner::TopRight => bounds.y,
+            Corner::BottomRight | Corner::BottomLeft => bounds.y + bounds.height - size,
+        };
+        Rectangle {
+            x,
+            y,
+            width: size,
+            height: size,
+        }
+    }
+}
+
+/// The radius of a corner, if the corner is selected.
+fn corner(selected: bool, radius: f32) -> f32 {
+    if selected { radius } else { 0.0 }
+}
+
+/// Selects the edges of a [`Cell`] that have a border drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sides {
+    /// Draw the top edge.
+    pub top: bool,
+    /// Draw the right edge.
+    pub right: bool,
+    /// Draw the bottom edge.
+    pub bottom: bool,
+    /// Draw the left edge.
+    pub left: bool,
+}
+
+impl Sides {
+    /// All the edges are drawn.
+    pub const ALL: Self = Self {
+        top: true,
+        right: true,
+        bottom: true,
+        left: true,
+    };
+
+    /// None of the edges are drawn.
+    pub const NONE: Self = Self {
+        top: false,
+        right: false,
+        bottom: false,
+        left: false,
+    };
+
+    /// Only the right and bottom edges are drawn.
+    pub const RIGHT_BOTTOM: Self = Self {
+        top: false,
+        right: true,
+        bottom: true,
+        left: false,
+    };
+
+    /// The edges of a cell in a [`Grid`] without spacing, where each cell draws
+    /// its right and bottom edges, while the cells of the first row and the
+    /// first column also draw the outer top and left edges.
+    pub fn grid(row: usize, column: usize) -> Self {
+        Self {
+            top: row == 0,
+            left: column == 0,
+            ..Self::RIGHT_BOTTOM
+        }
+    }
+}
+
+impl Default for Sides {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Selects the corners of a [`Cell`] that use the border radius.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Corners {
+    /// Round the top left corner.
+    pub top_left: bool,
+    /// Round the top right corner.
+    pub top_right: bool,
+    /// Round the bottom right corner.
+    pub bottom_right: bool,
+    /// Round the bottom left corner.
+    pub bottom_left: bool,
+}
+
+impl Corners {
+    /// All the corners are rounded.
+    pub const ALL: Self = Self {
+        top_left: true,
+        top_right: true,
+        bottom_right: true,
+        bottom_left: true,
+    };
+
+    /// None of the corners are rounded.
+    pub const NONE: Self = Self {
+        top_left: false,
+        top_right: false,
+        bottom_right: false,
+        bottom_left: false,
+    };
+
+    /// The corners of a cell in a [`Grid`] of the given number of rows and
+    /// columns, where only the outer corners of the table are rounded.
+    pub fn grid(row: usize, column: usize, rows: usize, columns: usize) -> Self {
+        let last_row = row + 1 == rows;
+        let last_column = column + 1 == columns;
+        Self {
+            top_left: row == 0 && column == 0,
+            top_right: row == 0 && last_column,
+            bottom_right: last_row && last_column,
+            bottom_left: last_row && column == 0,
+        }
+    }
+}
+
+impl Default for Corners {
+    fn default() -> Self {
+        Self::ALL
+    }
 }
 
 /// Just a smaller persistent state for the `Tree`.
 #[derive(Debug, Clone, Copy, Default)]
 struct State {
+    density: Density,
+    padding: Padding,
+    resize_hover_size: f32,
     is_pressed: bool,
+    is_focused: bool,
+    revision: Option<u64>,
+    flash_started: Option<Instant>,
+    flash_fade: Option<f32>,
+    overflow: bool,
+    hovered_since: Option<Instant>,
+    is_tooltip_open: bool,
+    is_marker_hovered: bool,
+    is_marker_pinned: bool,
+    drag_press: Option<Point>,
+    is_dragging: bool,
+    is_drag_over: bool,
+    is_validation_open: bool,
+    is_content_focused: bool,
+    rested_since: Option<Instant>,
+    is_hover_intent_published: bool,
     drag_origin_horizontal: Option<Point>,
     is_resize_hovered_horizontal: bool,
     drag_origin_vertical: Option<Point>,
@@ -281,15 +1213,26 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::default())
+        tree::State::new(State {
+            revision: self.flash.map(|(_, revision)| revision),
+            ..State::default()
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.content)]
+        self.elements().into_iter().map(Tree::new).collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(std::slice::from_ref(&self.content));
+        if let Some((_, revision)) = self.flash {
+            let state = tree.state.downcast_mut::<State>();
+            if state.revision.is_some_and(|previous| previous != revision) {
+                state.flash_started = Some(Instant::now());
+                state.flash_fade = Some(1.0);
+            }
+            state.revision = Some(revision);
+        }
+        tree.diff_children(&self.elements());
     }
 
     fn size(&self) -> Size<Length> {
@@ -305,7 +1248,46 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::padded(limits, Length::Fill, Length::Fill, self.padding, |limits| {
+        // The inherited density is only available during layout.
+        let density = Density::current();
+        let padding = self.padding.unwrap_or(density.cell_padding);
+        let state = tree.state.downcast_mut::<State>();
+        state.density = density;
+        state.padding = padding;
+        state.resize_hover_size = self.resize_hover_size.unwrap_or(density.resize_hover_size);
+
+        // The natural size of the content is measured first, so the last layout
+        // of the content is the one within the cell.
+        if self.ellipsis || self.tooltip.is_some() {
+            let available = limits.shrink(padding).max();
+            let content_size = self.content.as_widget().size();
+            let natural = self
+                .content
+                .as_widget()
+                .layout(
+                    &mut tree.children[0],
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(
+                            if content_size.width.is_fill() {
+                                available.width
+                            } else {
+                                f32::INFINITY
+                            },
+                            if content_size.height.is_fill() {
+                                available.height
+                            } else {
+                                f32::INFINITY
+                            },
+                        ),
+                    ),
+                )
+                .size();
+            let state = tree.state.downcast_mut::<State>();
+            state.overflow = natural.width > available.width || natural.height > available.height;
+        }
+        layout::padded(limits, Length::Fill, Length::Fill, padding, |limits| {
             self.content
                 .as_widget()
                 .layout(&mut tree.children[0], renderer, limits)
@@ -340,111 +1322,401 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        self.content.as_widget_mut().update(
-            &mut tree.children[0],
-            event,
-            layout.children().next().unwrap(),
-            cursor,
-            renderer,
-            clipboard,
-            shell,
-            viewport,
-        );
-        if shell.is_event_captured() {
-            return;
-        }
         let state = tree.state.downcast_mut::<State>();
         let bounds = layout.bounds();
+
+        // The markers are drawn above the content, thus receive events first.
+        let mut is_marker_pressed = false;
+        if self.marker_content.is_some() {
+            let is_over_marker = self
+                .markers
+                .iter()
+                .any(|marker| cursor.is_over(marker.bounds(bounds)));
+            if let Event::Mouse(_) = event
+                && is_over_marker != state.is_marker_hovered
+            {
+                state.is_marker_hovered = is_over_marker;
+                shell.request_redraw();
+            }
+            if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            {
+                if is_over_marker && !shell.is_event_captured() {
+                    state.is_marker_pinned = !state.is_marker_pinned;
+                    shell.invalidate_layout();
+                    shell.capture_event();
+                    is_marker_pressed = true;
+                } else if state.is_marker_pinned {
+                    state.is_marker_pinned = false;
+                    shell.invalidate_layout();
+                }
+            }
+        }
+
+        if !is_marker_pressed {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
         let resize_hover_bounds_horizontal = Rectangle {
-            x: bounds.x + bounds.width - self.resize_hover_size,
-            width: 2.0 * self.resize_hover_size,
+            x: bounds.x + bounds.width - state.resize_hover_size,
+            width: 2.0 * state.resize_hover_size,
             ..bounds
         };
-        if self.on_resize_horizontal.is_some() {
-            state.is_resize_hovered_horizontal = cursor.is_over(resize_hover_bounds_horizontal);
-        }
         let resize_hover_bounds_vertical = Rectangle {
-            y: bounds.y + bounds.height - self.resize_hover_size,
-            height: 2.0 * self.resize_hover_size,
+            y: bounds.y + bounds.height - state.resize_hover_size,
+            height: 2.0 * state.resize_hover_size,
             ..bounds
         };
-        if self.on_resize_vertical.is_some() {
-            state.is_resize_hovered_vertical = cursor.is_over(resize_hover_bounds_vertical);
-        }
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if self.on_resize_horizontal.is_some()
-                    && let Some(origin) = cursor.position_over(resize_hover_bounds_horizontal)
-                {
-                    state.drag_origin_horizontal = Some(origin);
-                    shell.capture_event();
-                }
-                if self.on_resize_vertical.is_some()
-                    && let Some(origin) = cursor.position_over(resize_hover_bounds_vertical)
-                {
-                    state.drag_origin_vertical = Some(origin);
-                    shell.capture_event();
+
+        // The tooltip of overflowing content opens after hovering for the delay.
+        if self.tooltip.is_some()
+            && let Event::Mouse(_) | Event::Window(window::Event::RedrawRequested(_)) = event
+        {
+            let now = Instant::now();
+            if state.overflow && cursor.is_over(bounds) && !state.is_pressed {
+                match state.hovered_since {
+                    None => {
+                        state.hovered_since = Some(now);
+                        shell.request_redraw_at(now + self.tooltip_delay);
+                    }
+                    Some(since) if !state.is_tooltip_open => {
+                        let elapsed = now.saturating_duration_since(since);
+                        if elapsed < self.tooltip_delay {
+                            shell.request_redraw_at(now + self.tooltip_delay - elapsed);
+                        } else {
+                            state.is_tooltip_open = true;
+                            shell.invalidate_layout();
+                        }
+                    }
+                    Some(_) => {}
                 }
-                let bounds = layout.bounds();
-                if cursor.is_over(bounds) {
-                    let state = tree.state.downcast_mut::<State>();
-                    state.is_pressed = true;
-                    shell.capture_event();
+            } else {
+                state.hovered_since = None;
+                if state.is_tooltip_open {
+                    state.is_tooltip_open = false;
+                    shell.invalidate_layout();
+                    shell.request_redraw();
                 }
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                if self.on_resize_horizontal.is_some()
-                    && state.drag_origin_horizontal.take().is_some()
-                {
-                    shell.publish(self.on_resize_horizontal.as_ref().unwrap().1.clone());
-                    shell.capture_event();
+        }
+
+        // Entering and leaving are tracked, even if the event was captured.
+        if let Event::Mouse(_) | Event::Window(window::Event::RedrawRequested(_)) = event {
+            let now = Instant::now();
+            match (state.rested_since, cursor.is_over(bounds)) {
+                (None, true) => {
+                    state.rested_since = Some(now);
+                    state.is_hover_intent_published = false;
+                    if let Some(message) = &self.on_enter {
+                        shell.publish(message.clone());
+                    }
+                    if let Some((_, delay)) = &self.on_hover_intent {
+                        shell.request_redraw_at(now + *delay);
+                    }
                 }
-                if self.on_resize_vertical.is_some() && state.drag_origin_vertical.take().is_some()
-                {
-                    shell.publish(self.on_resize_vertical.as_ref().unwrap().1.clone());
-                    shell.capture_event();
+                (Some(_), false) => {
+                    state.rested_since = None;
+                    if let Some(message) = &self.on_exit {
+                        shell.publish(message.clone());
+                    }
                 }
-                if state.is_pressed {
-                    state.is_pressed = false;
-                    let bounds = layout.bounds();
-                    if cursor.is_over(bounds) {
-                        if let Some(message) = &self.on_press {
+                (Some(rested_since), true) => {
+                    if let Some((message, delay)) = &self.on_hover_intent
+                        && !state.is_hover_intent_published
+                    {
+                        // Moving within the cell restarts the delay.
+                        let rested_since =
+                            if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+                                state.rested_since = Some(now);
+                                now
+                            } else {
+                                rested_since
+                            };
+                        let elapsed = now.saturating_duration_since(rested_since);
+                        if elapsed < *delay {
+                            shell.request_redraw_at(now + *delay - elapsed);
+                        } else {
+                            state.is_hover_intent_published = true;
                             shell.publish(message.clone());
                         }
                     }
-                    shell.capture_event();
                 }
+                (None, false) => {}
             }
-            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                if self.on_resize_horizontal.is_some() {
-                    if let Some(position) = cursor.position() {
-                        if let Some(origin) = state.drag_origin_horizontal {
-                            shell.publish((self.on_resize_horizontal.as_ref().unwrap().0)(
-                                (position - origin).x,
-                            ));
-                            shell.capture_event();
-                        }
+        }
+
+        // Any cell ends the drag, as the source may be removed by the drop. The
+        // payload is kept during the release, until every cell has seen it.
+        DRAG.with(|drag| {
+            let mut drag = drag.borrow_mut();
+            match event {
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                    if let Some(drag) = drag.as_mut() {
+                        drag.is_released = true;
                     }
                 }
-                if self.on_resize_vertical.is_some() {
-                    if let Some(position) = cursor.position() {
-                        if let Some(origin) = state.drag_origin_vertical {
-                            shell.publish((self.on_resize_vertical.as_ref().unwrap().0)(
-                                (position - origin).y,
-                            ));
+                Event::Mouse(mouse::Event::CursorLeft)
+                | Event::Touch(touch::Event::FingerLost { .. }) => *drag = None,
+                _ if drag.as_ref().is_some_and(|drag| drag.is_released) => *drag = None,
+                _ => {}
+            }
+        });
+
+        // Dragging and dropping is tracked, even if the event was captured, as
+        // other widgets are not aware of the drag.
+        let was_dragging = state.is_dragging;
+        if let Some(payload) = &self.drag_source {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if let (Some(origin), Some(position)) = (state.drag_press, cursor.position())
+                        && !state.is_dragging
+                        && origin.distance(position) > DRAG_THRESHOLD
+                    {
+                        state.is_dragging = true;
+                        state.is_pressed = false;
+                        DRAG.with(|drag| {
+                            *drag.borrow_mut() = Some(Drag {
+                                payload: Rc::clone(payload),
+                                is_released: false,
+                            })
+                        });
+                        shell.request_redraw();
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Mouse(mouse::Event::CursorLeft)
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.drag_press = None;
+                    if state.is_dragging {
+                        state.is_dragging = false;
+                        shell.request_redraw();
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let (Some(on_drop), Some(accepts)) = (&self.on_drop, self.accepts)
+            && let Event::Mouse(_) | Event::Touch(_) = event
+        {
+            // The source of the drag is not a target of its own payload.
+            let payload = DRAG
+                .with(|drag| drag.borrow().as_ref().map(|drag| Rc::clone(&drag.payload)))
+                .filter(|payload| {
+                    !was_dragging
+                        && !state.is_dragging
+                        && !self.disabled
+                        && cursor.is_over(bounds)
+                        && accepts(payload.as_ref())
+                });
+            match (payload, state.is_drag_over) {
+                (Some(payload), is_drag_over) => {
+                    if !is_drag_over {
+                        state.is_drag_over = true;
+                        if let Some(on_drag_enter) = &self.on_drag_enter {
+                            shell.publish(on_drag_enter.clone());
+                        }
+                    }
+                    match event {
+                        Event::Mouse(mouse::Event::CursorMoved { .. })
+                        | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                            if let (Some(on_drag_over), Some(position)) =
+                                (&self.on_drag_over, cursor.position())
+                            {
+                                shell.publish(on_drag_over(
+                                    Point::ORIGIN + (position - bounds.position()),
+                                ));
+                            }
+                        }
+                        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                        | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                            state.is_drag_over = false;
+                            if let Some(message) = on_drop(payload.as_ref()) {
+                                shell.publish(message);
+                            }
                             shell.capture_event();
                         }
+                        _ => {}
                     }
                 }
+                (None, true) => state.is_drag_over = false,
+                (None, false) => {}
+            }
+        }
+
+        // Pressing elsewhere removes the focus, even if the press was captured.
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if !cursor.is_over(bounds) {
+                state.is_focused = false;
             }
-            Event::Touch(touch::Event::FingerLost { .. }) => {
-                let state = tree.state.downcast_mut::<State>();
+        }
+
+        let mut update = || {
+            if self.disabled {
+                // Any on-going interaction is dropped when becoming disabled.
                 state.is_pressed = false;
+                state.is_focused = false;
+                state.drag_origin_horizontal = None;
+                state.is_resize_hovered_horizontal = false;
+                state.drag_origin_vertical = None;
+                state.is_resize_hovered_vertical = false;
+                state.drag_press = None;
+                return;
+            }
+
+            // On-going resize drags track the pointer wherever it goes until
+            // released, even when the event was captured by other widgets.
+            if state.drag_origin_horizontal.is_some() || state.drag_origin_vertical.is_some() {
+                match event {
+                    Event::Mouse(mouse::Event::CursorMoved { position })
+                    | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                        if let (Some(origin), Some((on_drag, _))) =
+                            (state.drag_origin_horizontal, &self.on_resize_horizontal)
+                        {
+                            shell.publish(on_drag((*position - origin).x));
+                        }
+                        if let (Some(origin), Some((on_drag, _))) =
+                            (state.drag_origin_vertical, &self.on_resize_vertical)
+                        {
+                            shell.publish(on_drag((*position - origin).y));
+                        }
+                        shell.capture_event();
+                    }
+                    Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                    | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                        if let (Some(_), Some((_, on_release))) = (
+                            state.drag_origin_horizontal.take(),
+                            &self.on_resize_horizontal,
+                        ) {
+                            shell.publish(on_release.clone());
+                        }
+                        if let (Some(_), Some((_, on_release))) =
+                            (state.drag_origin_vertical.take(), &self.on_resize_vertical)
+                        {
+                            shell.publish(on_release.clone());
+                        }
+                        state.is_pressed = false;
+                        shell.capture_event();
+                    }
+                    Event::Mouse(mouse::Event::CursorLeft)
+                    | Event::Touch(touch::Event::FingerLost { .. }) => {
+                        state.drag_origin_horizontal = None;
+                        state.drag_origin_vertical = None;
+                        state.is_pressed = false;
+                        if let Some(message) = &self.on_resize_cancel {
+                            shell.publish(message.clone());
+                        }
+                    }
+                    _ => {}
+                }
+                return;
+            }
+
+            if shell.is_event_captured() {
+                return;
             }
-            _ => {}
+            if self.on_resize_horizontal.is_some() {
+                state.is_resize_hovered_horizontal = cursor.is_over(resize_hover_bounds_horizontal);
+            }
+            if self.on_resize_vertical.is_some() {
+                state.is_resize_hovered_vertical = cursor.is_over(resize_hover_bounds_vertical);
+            }
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    if self.on_resize_horizontal.is_some()
+                        && let Some(origin) = cursor.position_over(resize_hover_bounds_horizontal)
+                    {
+                        state.drag_origin_horizontal = Some(origin);
+                        shell.capture_event();
+                    }
+                    if self.on_resize_vertical.is_some()
+                        && let Some(origin) = cursor.position_over(resize_hover_bounds_vertical)
+                    {
+                        state.drag_origin_vertical = Some(origin);
+                        shell.capture_event();
+                    }
+                    if self.drag_source.is_some() && !self.disabled {
+                        state.drag_press = cursor.position_over(bounds);
+                    }
+                    if cursor.is_over(bounds) && !self.is_disabled() {
+                        state.is_pressed = true;
+                        state.is_focused = self.clickable;
+                        shell.capture_event();
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                    if state.is_pressed =>
+                {
+                    state.is_pressed = false;
+                    if cursor.is_over(bounds) {
+                        if let Some(message) = &self.on_press {
+                            shell.publish(message.clone());
+                        }
+                    }
+                    shell.capture_event();
+                }
+                Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.is_pressed = false;
+                }
+                _ => {}
+            };
         };
+
+        update();
+
+        // The content, such as a text input, keeps the focus of its own.
+        if self.validation_message.is_some() {
+            let mut find_focus = FindFocus::default();
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                &mut find_focus,
+            );
+            state.is_content_focused = find_focus.is_focused;
+        }
+        let is_validation_open = self.validation_message.is_some()
+            && (state.is_focused || state.is_content_focused || state.rested_since.is_some());
+        if is_validation_open != state.is_validation_open {
+            state.is_validation_open = is_validation_open;
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        let status = self.status(state, cursor.is_over(bounds));
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            self.last_status = Some(status);
+            if let (Some((flash, _)), Some(started)) = (self.flash, state.flash_started) {
+                let elapsed = now.saturating_duration_since(started);
+                if elapsed < flash.duration {
+                    state.flash_fade =
+                        Some(1.0 - elapsed.as_secs_f32() / flash.duration.as_secs_f32());
+                    shell.request_redraw();
+                } else {
+                    state.flash_started = None;
+                    state.flash_fade = None;
+                }
+            }
+        } else if self
+            .last_status
+            .is_some_and(|last_status| last_status != status)
+        {
+            shell.request_redraw();
+        }
     }
 
     fn mouse_interaction(
@@ -456,12 +1728,14 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<State>();
-        if state.drag_origin_horizontal.is_some() || state.is_resize_hovered_horizontal {
+        if state.is_dragging {
+            mouse::Interaction::Grabbing
+        } else if state.drag_origin_horizontal.is_some() || state.is_resize_hovered_horizontal {
             mouse::Interaction::ResizingHorizontally
         } else if state.drag_origin_vertical.is_some() || state.is_resize_hovered_vertical {
             mouse::Interaction::ResizingVertically
         } else if self.clickable {
-            if cursor.is_over(layout.bounds()) {
+            if cursor.is_over(layout.bounds()) && !self.is_disabled() {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
@@ -489,39 +1763,59 @@ where
     ) {
         let bounds = layout.bounds();
         if let Some(clipped_viewport) = bounds.intersection(viewport) {
-            let mut style = style::Catalog::style(theme, self.styling);
-            if let Some(color) = self.change_color {
-                match self.styling {
-                    style::Styling::Value(change) | style::Styling::RowAlternating(change, _) => {
-                        if change {
-                            let border = Border {
-                                color,
-                                ..style.border
-                            };
-                            style = style.border(border);
-                        }
-                    }
-                    _ => {}
-                }
+            let status = self.last_status.unwrap_or(style::Status::Active);
+            let mut style = theme.style(&self.class, status);
+            if self.changed
+                && let Some(color) = self.change_color
+            {
+                let border = Border {
+                    color,
+                    ..style.border
+                };
+                style = style.border(border).edges(style.edges.color(color));
             }
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds,
-                    border: style.border,
-                    shadow: Shadow::default(),
-                    snap: false,
-                },
-                style.background,
-            );
-            self.content.as_widget().draw(
-                &tree.children[0],
+            if let Some(severity) = self.severity {
+                style = theme.validation(style, severity);
+            }
+            let state = tree.state.downcast_ref::<State>();
+            if let (Some((flash, _)), Some(fade)) = (self.flash, state.flash_fade) {
+                style = flash.apply(style, fade);
+            }
+            self.draw_background(renderer, bounds, &style);
+            self.draw_data_bar(
                 renderer,
-                theme,
-                renderer_style,
-                layout.children().next().unwrap(),
-                cursor,
-                &clipped_viewport,
+                bounds.shrink(state.padding),
+                theme.data_bar(status),
             );
+            let renderer_style = renderer::Style {
+                text_color: style::text_color(&style, renderer_style.text_color),
+            };
+            if self.ellipsis && state.overflow {
+                let content_bounds = bounds.shrink(state.padding);
+                renderer.with_layer(content_bounds, |renderer| {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        &renderer_style,
+                        layout.children().next().unwrap(),
+                        cursor,
+                        &clipped_viewport,
+                    );
+                });
+                self.draw_ellipsis(renderer, content_bounds, &style, renderer_style.text_color);
+            } else {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    &renderer_style,
+                    layout.children().next().unwrap(),
+                    cursor,
+                    &clipped_viewport,
+                );
+            }
+            self.draw_markers(renderer, bounds);
         }
     }
 
@@ -533,13 +1827,163 @@ where
         viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        self.content.as_widget_mut().overlay(
-            &mut tree.children[0],
+        let state = *tree.state.downcast_ref::<State>();
+        let mut children = tree.children.iter_mut();
+        let content = self.content.as_widget_mut().overlay(
+            children.next().unwrap(),
             layout.children().next().unwrap(),
             renderer,
             viewport,
             translation,
+        );
+        let tooltip_tree = self.tooltip.as_ref().and_then(|_| children.next());
+        let marker_tree = self.marker_content.as_ref().and_then(|_| children.next());
+        let validation_tree = self
+            .validation_message
+            .as_ref()
+            .and_then(|_| children.next());
+
+        // Only a single tooltip is shown, preferring the marker content, then
+        // the full content, and lastly the validation message.
+        let tooltip = if let (Some(tooltip), Some(tree)) = (&mut self.marker_content, marker_tree)
+            && (state.is_marker_hovered || state.is_marker_pinned)
+        {
+            Some((tooltip, tree, None))
+        } else if let (Some(tooltip), Some(tree)) = (&mut self.tooltip, tooltip_tree)
+            && state.is_tooltip_open
+        {
+            Some((tooltip, tree, None))
+        } else if let (Some(tooltip), Some(tree)) = (&mut self.validation_message, validation_tree)
+            && state.is_validation_open
+        {
+            Some((tooltip, tree, self.severity))
+        } else {
+            None
+        }
+        .map(|(tooltip, tree, severity)| {
+            overlay::Element::new(Box::new(Tooltip {
+                tooltip,
+                tree,
+                bounds: layout.bounds() + translation,
+                density: state.density,
+                padding: state.padding,
+                class: &self.class,
+                severity,
+            }))
+        });
+        if content.is_some() || tooltip.is_some() {
+            Some(
+                overlay::Group::with_children(content.into_iter().chain(tooltip).collect())
+                    .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+/// Finds whether a widget of the content of a [`Cell`] is focused.
+#[derive(Debug, Default)]
+struct FindFocus {
+    is_focused: bool,
+}
+
+impl Operation for FindFocus {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if !self.is_focused {
+            operate_on_children(self);
+        }
+    }
+
+    fn focusable(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        state: &mut dyn operation::Focusable,
+    ) {
+        self.is_focused |= state.is_focused();
+    }
+}
+
+/// The tooltip overlay below a [`Cell`], showing either the full content, the
+/// [`Marker`] content, or the [`Validation`] message.
+struct Tooltip<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    tooltip: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    bounds: Rectangle,
+    density: Density,
+    padding: Padding,
+    class: &'b Theme::Class<'a>,
+    severity: Option<style::Severity>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Tooltip<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds).shrink(self.padding);
+        let tooltip = density::scope(Some(self.density), || {
+            self.tooltip
+                .as_widget()
+                .layout(self.tree, renderer, &limits)
+        });
+        let size = tooltip.size().expand(self.padding);
+        layout::Node::with_children(
+            size,
+            vec![tooltip.translate(Vector::new(self.padding.left, self.padding.top))],
         )
+        .translate(shared::overlay_offset(
+            self.bounds,
+            self.bounds.x,
+            size,
+            bounds,
+        ))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let mut style = theme.style(self.class, style::Status::Active);
+        if let Some(severity) = self.severity {
+            style = theme.validation(style, severity);
+        }
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: style.border,
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            style.background,
+        );
+        let renderer_style = renderer::Style {
+            text_color: style::text_color(&style, renderer_style.text_color),
+        };
+        self.tooltip.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            &renderer_style,
+            layout.children().next().unwrap(),
+            cursor,
+            &layout.bounds(),
+        );
     }
 }
 
@@ -13,12 +13,18 @@ use iced_widget::text_input;
 /// The appearance of a cell container.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
-    /// The text [`Color`] of the container.
+    /// The text [`Color`] of the container, which is inherited by the content.
+    ///
+    /// When left as the default, a text colour contrasting with the background
+    /// is used instead. See [`text_color()`].
     pub text_color: Color,
     /// The [`Background`] of the container.
     pub background: Background,
     /// The [`Border`] of the container.
     pub border: Border,
+    /// The per-edge borders of the container, which take precedence over the
+    /// width and colour of the [`Border`].
+    pub edges: Edges,
 }
 
 impl Style {
@@ -45,6 +51,11 @@ impl Style {
             ..self
         }
     }
+
+    /// Updates the [`Style`] with the given [`Edges`].
+    pub fn edges(self, edges: Edges) -> Self {
+        Self { edges, ..self }
+    }
 }
 
 impl Default for Style {
@@ -53,11 +64,79 @@ impl Default for Style {
             text_color: Color::default(),
             background: Background::Color(Color::TRANSPARENT),
             border: Border::default(),
+            edges: Edges::default(),
+        }
+    }
+}
+
+/// The border of a single edge of a [`Cell`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Edge {
+    /// The [`Color`] of the edge.
+    pub color: Color,
+    /// The width of the edge.
+    pub width: f32,
+}
+
+impl Edge {
+    /// Creates an [`Edge`] of the given [`Color`] and width.
+    pub fn new(color: impl Into<Color>, width: f32) -> Self {
+        Self {
+            color: color.into(),
+            width,
+        }
+    }
+}
+
+impl From<Border> for Edge {
+    fn from(border: Border) -> Self {
+        Self {
+            color: border.color,
+            width: border.width,
         }
     }
 }
 
-/// Style selecting
+/// The borders of the individual edges of a [`Cell`].
+///
+/// An edge set to `None` uses the width and colour of [`Style::border`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Edges {
+    /// The top [`Edge`].
+    pub top: Option<Edge>,
+    /// The right [`Edge`].
+    pub right: Option<Edge>,
+    /// The bottom [`Edge`].
+    pub bottom: Option<Edge>,
+    /// The left [`Edge`].
+    pub left: Option<Edge>,
+}
+
+impl Edges {
+    /// Creates [`Edges`] with the same [`Edge`] on all sides.
+    pub fn all(edge: impl Into<Edge>) -> Self {
+        let edge = Some(edge.into());
+        Self {
+            top: edge,
+            right: edge,
+            bottom: edge,
+            left: edge,
+        }
+    }
+
+    /// Sets the [`Color`] of all the set edges.
+    pub fn color(self, color: Color) -> Self {
+        let recolor = |edge: Option<Edge>| edge.map(|edge| Edge { color, ..edge });
+        Self {
+            top: recolor(self.top),
+            right: recolor(self.right),
+            bottom: recolor(self.bottom),
+            left: recolor(self.left),
+        }
+    }
+}
+
+/// The ready-made classes of the [`Cell`], for use with [`Cell::styling()`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Styling {
     /// The [`Cell`] is a label.
@@ -72,118 +151,341 @@ pub enum Styling {
     RowAlternating(bool, usize), // The `uszie` is row number.
 }
 
-/// The theme catalog of cell grid widgets.
-pub trait Catalog {
-    /// Select a specific style to apply to the widget.
-    fn style(&self, styling: Styling) -> Style {
-        match styling {
-            Styling::Label => Self::label(self),
-            Styling::ReadOnly => Self::read_only(self),
-            Styling::Divider(hovered) => Self::divider(self, hovered),
-            Styling::Value(changed) => Self::value(self, changed),
-            Styling::RowAlternating(changed, index) => Self::row_alternating(self, changed, index),
+impl Styling {
+    /// The [`Style`] of the ready-made class for the given [`Status`].
+    pub fn style(self, theme: &Theme, status: Status) -> Style {
+        match self {
+            Styling::Label => label(theme, status),
+            Styling::ReadOnly => read_only(theme, status),
+            Styling::Divider(hovered) => divider(theme, status, hovered),
+            Styling::Value(changed) => value(theme, status, changed),
+            Styling::RowAlternating(changed, index) => {
+                row_alternating(theme, status, changed, index)
+            }
         }
     }
 
-    /// The style for the labels.
-    fn label(&self) -> Style;
+    /// Indicates whether the ready-made class is for changed content.
+    pub fn is_changed(self) -> bool {
+        match self {
+            Styling::Value(changed) | Styling::RowAlternating(changed, _) => changed,
+            _ => false,
+        }
+    }
+}
 
-    /// The style for read only cells.
-    fn read_only(&self) -> Style;
+/// The possible status of a [`Cell`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Cell`] is idle.
+    Active,
+    /// The [`Cell`] is being hovered.
+    Hovered,
+    /// The [`Cell`] is being pressed.
+    Pressed,
+    /// The [`Cell`] was the last clickable cell to be pressed.
+    Focused {
+        /// Indicates whether the [`Cell`] is also being hovered.
+        is_hovered: bool,
+    },
+    /// The [`Cell`] is disabled, or is clickable though has nothing to emit
+    /// when pressed.
+    Disabled,
+    /// An accepted drag is over the [`Cell`], which is a drop target.
+    DropTarget,
+}
+
+/// The theme catalog of a [`Cell`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
 
-    /// The style for editable value.
-    fn value(&self, changed: bool) -> Style;
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
 
-    /// The style for editable value with alternative row background color.
-    fn row_alternating(&self, changed: bool, index: usize) -> Style;
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`DataBar`] colours with the given status.
+    fn data_bar(&self, status: Status) -> DataBar;
+
+    /// Adjusts the [`Style`] of a class for the [`Severity`] of the validation.
+    fn validation(&self, style: Style, severity: Severity) -> Style;
+}
+
+/// The severity of the validation result of a [`Cell`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The content is valid.
+    Ok,
+    /// The content is questionable.
+    Warning,
+    /// The content is invalid.
+    Error,
+}
 
-    /// The style for the label divider.
-    fn divider(&self, hovered: bool) -> Style;
+/// The appearance of the data bar of a [`Cell`], drawn under the content.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DataBar {
+    /// The [`Background`] of the bar for positive values.
+    pub positive: Background,
+    /// The [`Background`] of the bar for negative values.
+    pub negative: Background,
 }
 
+/// A styling function for a [`Cell`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
 impl Catalog for Theme {
-    fn label(&self) -> Style {
-        let extended = self.extended_palette();
-        Style {
-            background: Background::Color(extended.secondary.base.color),
-            border: Border {
-                color: self.palette().text,
-                width: 1.0,
-                radius: Radius::new(0),
-            },
-            ..Style::default()
-        }
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(read_only)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
     }
 
-    fn read_only(&self) -> Style {
+    fn data_bar(&self, status: Status) -> DataBar {
         let extended = self.extended_palette();
-        Style {
-            background: Background::Color(extended.primary.base.color),
-            border: Border {
-                color: self.palette().text,
-                width: 1.0,
-                radius: Radius::new(0),
-            },
-            ..Style::default()
+        let alpha = match status {
+            Status::Disabled => 0.3,
+            _ => 0.6,
+        };
+        DataBar {
+            positive: Background::Color(extended.success.base.color.scale_alpha(alpha)),
+            negative: Background::Color(extended.danger.base.color.scale_alpha(alpha)),
         }
     }
 
-    fn value(&self, changed: bool) -> Style {
-        let palette = self.palette();
+    fn validation(&self, style: Style, severity: Severity) -> Style {
         let extended = self.extended_palette();
-        let border = if changed {
-            palette.danger
-            //extended.danger.base.color
-        } else {
-            palette.text
+        let color = match severity {
+            Severity::Ok => return style,
+            Severity::Warning => extended.warning.base.color,
+            Severity::Error => extended.danger.base.color,
         };
         Style {
-            background: Background::Color(extended.background.base.color),
+            background: tint(style.background, color, 0.2),
             border: Border {
-                color: border,
-                width: 1.0,
-                radius: Radius::new(0),
+                color,
+                width: style.border.width.max(2.0),
+                ..style.border
             },
-            ..Style::default()
+            edges: style.edges.color(color),
+            ..style
         }
     }
+}
 
-    fn row_alternating(&self, changed: bool, index: usize) -> Style {
-        let palette = self.palette();
-        let extended = self.extended_palette();
-        let border = if changed {
-            palette.danger
-            //extended.danger.base.color
-        } else {
-            palette.text
-        };
-        let background = if index % 2 == 0 {
-            extended.background.base.color
-        } else {
-            extended.background.weak.color
-        };
-        Style {
-            background: background.into(),
+impl<'a> From<Styling> for StyleFn<'a, Theme> {
+    fn from(styling: Styling) -> Self {
+        Box::new(move |theme, status| styling.style(theme, status))
+    }
+}
+
+/// The style for the labels.
+pub fn label(theme: &Theme, status: Status) -> Style {
+    let extended = theme.extended_palette();
+    let style = Style {
+        text_color: extended.secondary.base.text,
+        background: Background::Color(extended.secondary.base.color),
+        border: Border {
+            color: theme.palette().text,
+            width: 1.0,
+            radius: Radius::new(0),
+        },
+        ..Style::default()
+    };
+    status_style(theme, style, status)
+}
+
+/// The style for read only cells.
+pub fn read_only(theme: &Theme, status: Status) -> Style {
+    let extended = theme.extended_palette();
+    let style = Style {
+        text_color: extended.primary.base.text,
+        background: Background::Color(extended.primary.base.color),
+        border: Border {
+            color: theme.palette().text,
+            width: 1.0,
+            radius: Radius::new(0),
+        },
+        ..Style::default()
+    };
+    status_style(theme, style, status)
+}
+
+/// The style for editable value.
+pub fn value(theme: &Theme, status: Status, changed: bool) -> Style {
+    let palette = theme.palette();
+    let extended = theme.extended_palette();
+    let border = if changed {
+        palette.danger
+        //extended.danger.base.color
+    } else {
+        palette.text
+    };
+    let style = Style {
+        text_color: extended.background.base.text,
+        background: Background::Color(extended.background.base.color),
+        border: Border {
+            color: border,
+            width: 1.0,
+            radius: Radius::new(0),
+        },
+        ..Style::default()
+    };
+    status_style(theme, style, status)
+}
+
+/// The style for editable value with alternative row background color.
+pub fn row_alternating(theme: &Theme, status: Status, changed: bool, index: usize) -> Style {
+    let palette = theme.palette();
+    let extended = theme.extended_palette();
+    let border = if changed {
+        palette.danger
+        //extended.danger.base.color
+    } else {
+        palette.text
+    };
+    let background = if index % 2 == 0 {
+        extended.background.base
+    } else {
+        extended.background.weak
+    };
+    let style = Style {
+        text_color: background.text,
+        background: background.color.into(),
+        border: Border {
+            color: border,
+            width: 1.0,
+            radius: Radius::new(0),
+        },
+        ..Style::default()
+    };
+    status_style(theme, style, status)
+}
+
+/// The style for the label divider.
+///
+/// The divider is also considered hovered when the [`Status`] indicates so.
+pub fn divider(theme: &Theme, status: Status, hovered: bool) -> Style {
+    let extended = theme.extended_palette();
+    let hovered = hovered
+        || matches!(
+            status,
+            Status::Hovered | Status::Pressed | Status::Focused { is_hovered: true }
+        );
+    let background = if hovered {
+        extended.primary.base.color
+    } else {
+        extended.background.weak.color
+    };
+    Style {
+        background: background.into(),
+        ..Style::default()
+    }
+}
+
+/// Alters the base [`Style`] of the [`Theme`] to give feedback of the [`Status`].
+fn status_style(theme: &Theme, style: Style, status: Status) -> Style {
+    let extended = theme.extended_palette();
+    match status {
+        Status::Active => style,
+        Status::Hovered => Style {
+            background: tint(style.background, extended.primary.weak.color, 0.25),
+            ..style
+        },
+        Status::Pressed => Style {
+            background: tint(style.background, extended.primary.base.color, 0.4),
+            ..style
+        },
+        Status::Focused { is_hovered } => {
+            let style = Style {
+                border: Border {
+                    width: style.border.width.max(2.0),
+                    ..style.border
+                },
+                ..style
+            };
+            if is_hovered {
+                status_style(theme, style, Status::Hovered)
+            } else {
+                style
+            }
+        }
+        Status::DropTarget => Style {
+            background: tint(style.background, extended.primary.strong.color, 0.3),
             border: Border {
-                color: border,
-                width: 1.0,
-                radius: Radius::new(0),
+                color: extended.primary.strong.color,
+                width: style.border.width.max(2.0),
+                ..style.border
+            },
+            edges: style.edges.color(extended.primary.strong.color),
+            ..style
+        },
+        Status::Disabled => Style {
+            text_color: style.text_color.scale_alpha(0.5),
+            background: match style.background {
+                Background::Color(color) => Background::Color(color.scale_alpha(0.5)),
+                background => background,
             },
-            ..Style::default()
+            ..style
+        },
+    }
+}
+
+/// Resolves the text [`Color`] to be used for the content of a [`Cell`].
+///
+/// When [`Style::text_color`] is left as the default, either black or white
+/// is chosen to contrast with a solid background colour. The `inherited`
+/// colour is used for a gradient or mostly transparent background.
+pub fn text_color(style: &Style, inherited: Color) -> Color {
+    if style.text_color != Color::default() {
+        return style.text_color;
+    }
+    match style.background {
+        Background::Color(color) if color.a >= 0.5 => {
+            if relative_luminance(color) < 0.5 {
+                Color::WHITE
+            } else {
+                Color::BLACK
+            }
         }
+        _ => inherited,
     }
+}
 
-    fn divider(&self, hovered: bool) -> Style {
-        let extended = self.extended_palette();
-        let background = if hovered {
-            extended.primary.base.color
+/// The relative luminance of the colour, ignoring the alpha channel.
+fn relative_luminance(color: Color) -> f32 {
+    let linear = |channel: f32| {
+        if channel <= 0.04045 {
+            channel / 12.92
         } else {
-            extended.background.weak.color
-        };
-        Style {
-            background: background.into(),
-            ..Style::default()
+            ((channel + 0.055) / 1.055).powf(2.4)
         }
+    };
+    0.2126 * linear(color.r) + 0.7152 * linear(color.g) + 0.0722 * linear(color.b)
+}
+
+/// Mixes the [`Color`] into a [`Background::Color`] by the given factor.
+fn tint(background: Background, color: Color, factor: f32) -> Background {
+    match background {
+        Background::Color(base) => Background::Color(mix(base, color, factor)),
+        background => background,
+    }
+}
+
+/// Linear mix of two colors, where a factor of `0.0` is colour `a` and `1.0` is colour `b`.
+pub(crate) fn mix(a: Color, b: Color, factor: f32) -> Color {
+    let factor = factor.clamp(0.0, 1.0);
+    Color {
+        r: a.r + (b.r - a.r) * factor,
+        g: a.g + (b.g - a.g) * factor,
+        b: a.b + (b.b - a.b) * factor,
+        a: a.a + (b.a - a.a) * factor,
     }
 }
 
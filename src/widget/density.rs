@@ -0,0 +1,130 @@
+// This file is part of `iced_experimental` crate. For the terms of use, please see the file
+// called LICENSE-BSD-3-Clause at the top level of the `iced_experimental` crate.
+
+//! Density presets, which set the cell padding, the spacing between children,
+//! the resize hover zones and the scrollbar widths together.
+//!
+//! A [`Density`] is applied once on a container, such as a [`Grid`], an
+//! [`EqualHeightRow`], an [`EqualWidthColumn`] or a [`Scrollable`], and is
+//! inherited by all the widgets inside the container, unless a widget inside
+//! has its own [`Density`]. Values explicitly set on a widget, such as with
+//! [`Cell::padding()`], always take precedence over the [`Density`].
+//!
+//! Widgets outside of any container with a [`Density`] use the
+//! [`Density::COMFORTABLE`] preset.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::text;
+//! use iced_experimental_rizzen_yazston::widget::{Density, Grid, cell};
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     Grid::new()
+//!         .push_column_width(100.0)
+//!         .push_row_height(16.0)
+//!         .push(cell(false, text("Compact cell").into()))
+//!         .density(Density::COMPACT)
+//!         .into()
+//! }
+//! ```
+
+#[doc(inline)]
+#[allow(unused_imports)]
+use crate::widget::{Cell, EqualHeightRow, EqualWidthColumn, Grid, Scrollable};
+
+use crate::core::Padding;
+use std::cell::Cell as Ambient;
+
+thread_local! {
+    /// The [`Density`] of the container currently being laid out.
+    static CURRENT: Ambient<Option<Density>> = const { Ambient::new(None) };
+}
+
+/// The sizes used by the widgets for a level of density.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Density {
+    /// The [`Padding`] within a [`Cell`].
+    pub cell_padding: Padding,
+    /// The spacing between the children of a container.
+    pub spacing: f32,
+    /// The resize detection space on either side of the edge of a [`Cell`].
+    pub resize_hover_size: f32,
+    /// The width of the scrollbars and scrollers of a [`Scrollable`].
+    pub scrollbar_width: f32,
+}
+
+impl Density {
+    /// Small padding, no spacing, and thin scrollbars, for fitting many values
+    /// on screen.
+    pub const COMPACT: Self = Self {
+        cell_padding: Padding {
+            top: 1.0,
+            right: 1.0,
+            bottom: 1.0,
+            left: 1.0,
+        },
+        spacing: 0.0,
+        resize_hover_size: 3.0,
+        scrollbar_width: 6.0,
+    };
+
+    /// The default sizes of the widgets.
+    pub const COMFORTABLE: Self = Self {
+        cell_padding: Padding {
+            top: 2.0,
+            right: 2.0,
+            bottom: 2.0,
+            left: 2.0,
+        },
+        spacing: 0.0,
+        resize_hover_size: 5.0,
+        scrollbar_width: 10.0,
+    };
+
+    /// Large padding, spacing between children, and wide scrollbars, such as for
+    /// touch screens.
+    pub const SPACIOUS: Self = Self {
+        cell_padding: Padding {
+            top: 5.0,
+            right: 8.0,
+            bottom: 5.0,
+            left: 8.0,
+        },
+        spacing: 4.0,
+        resize_hover_size: 8.0,
+        scrollbar_width: 14.0,
+    };
+
+    /// The [`Density`] inherited from the enclosing containers.
+    ///
+    /// Only available while the widgets are being laid out, so widgets keep the
+    /// [`Density`] for laying out their overlays within [`scope()`].
+    pub(crate) fn current() -> Self {
+        CURRENT.get().unwrap_or_default()
+    }
+}
+
+impl Default for Density {
+    fn default() -> Self {
+        Self::COMFORTABLE
+    }
+}
+
+/// Runs `f` with the `density` as the inherited [`Density`], if any.
+pub(crate) fn scope<T>(density: Option<Density>, f: impl FnOnce() -> T) -> T {
+    let Some(density) = density else {
+        return f();
+    };
+    let previous = CURRENT.replace(Some(density));
+    let result = f();
+    CURRENT.set(previous);
+    result
+}
@@ -2,4 +2,6 @@
 // called LICENSE-BSD-3-Clause at the top level of the `iced_experimental` crate.
 
 pub mod column;
+#[allow(clippy::module_inception)]
+pub mod equal;
 pub mod row;
@@ -0,0 +1,2363 @@
+// This file is part of `iced_experimental` crate. For the terms of use, please see the file
+// called LICENSE-BSD-3-Clause at the top level of the `iced_experimental` crate.
+
+//! A container that distributes its contents along an [`Axis`], while ensuring
+//! all the children have an equal size across the axis, by expanding the
+//! children to the largest child.
+//!
+//! [`Equal`] is the generic widget behind [`EqualHeightRow`] (the
+//! [`Horizontal`] axis) and [`EqualWidthColumn`] (the [`Vertical`] axis), so
+//! both directions share the same features and fixes.
+//!
+//! [`Equal`] differs from [`Row`] and [`Column`] of `iced_widget` crate in the
+//! following way:
+//!
+//! * Intended to have alignment container widgets as its children, as this
+//!   widget alters the sizes of the children across the axis.
+//!
+//! * [`Length::Fill`] and [`Length::FillPortion`] are supported on the widget
+//!   size, using [`Equal::width()`] and [`Equal::height()`], and on the
+//!   children sizes along the axis, where the remaining space is distributed
+//!   by the portions. The children are still equalized across the axis.
+//!
+//! * [`Length::Fixed`] is support by using [`Equal::fixed_width()`] and
+//!   [`Equal::fixed_height()`], and when used on the widget size across the
+//!   axis the fixed value is used for all children.
+//!
+//! * When using [`Length::Shrink`] (default), there are additional options:
+//!
+//! ** Set optional maximum width, allowing extra flexibility than hard fixed
+//!    width using [`Length::Fixed`].
+//!
+//! ** Set optional maximum height, allowing extra flexibility than hard fixed
+//!    height using [`Length::Fixed`].
+//!
+//! ** Set optional minimum width, allowing extra flexibility than hard fixed
+//!    width using [`Length::Fixed`], but also allows entire widget to be
+//!    aligned horizontally if content is smaller than the minimum width.
+//!
+//! ** Set optional minimum height, allowing extra flexibility than hard fixed
+//!    height using [`Length::Fixed`], but also allows entire widget to be
+//!    aligned vertically if content is smaller than the minimum height.
+//!
+//! * The ability to reverse the current children of the widget.
+//!
+//! * Optionally equal sizes along the axis as well, using [`Equal::uniform()`],
+//!   for fully uniform tiles from content sized children.
+//!
+//! * Optionally flowing onto new lines when the available size runs out,
+//!   using [`Equal::wrap()`], with each line having its own size across the
+//!   axis, or all lines sharing the largest size.
+//!
+//! * Optionally sharing the size across the axis with other [`Equal`] widgets,
+//!   using a [`SizeGroup`], such as for the label columns of form sections.
+//!
+//! * Distributing the leftover space along the axis between the children,
+//!   using [`Equal::justify()`].
+//!
+//! * Lining up the contents of the children by their first text baselines,
+//!   using [`Equal::align_baseline()`], such as for a label next to a text input.
+//!
+//! * Optional draggable dividers between adjacent children, using
+//!   [`Equal::dividers()`], for multi-pane layouts of equal sized panes.
+//!
+//! * Optional reordering of the children by dragging, or with the `Alt` and
+//!   arrow keys, using [`Equal::on_reorder()`].
+//!
+//! * Optionally moving the children not fitting within the maximum size into
+//!   an overlay menu opened by a "…" button, using [`Equal::overflow_menu()`].
+//!
+//! * Supports a [`Density`] inherited by the widgets inside the container.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{container};
+//! use iced_experimental_rizzen_yazston::widget::{Equal, equal::equal::Vertical};
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     Equal::<Vertical, _, _, _>::with_children([
+//!         container("Top element").into(),
+//!         container("Centre element").into(),
+//!         container("Bottom element").into(),
+//!     ]).into()
+//! }
+//! ```
+
+#[doc(inline)]
+#[allow(unused_imports)]
+use crate::widget::{Cell, EqualHeightRow, EqualWidthColumn};
+#[doc(inline)]
+#[allow(unused_imports)]
+use iced_widget::{Column, Row};
+
+use crate::core::{
+    Clipboard,
+    Element,
+    Event,
+    Length,
+    Padding,
+    Pixels,
+    Point,
+    Rectangle,
+    Shadow,
+    Shell,
+    Size,
+    Vector,
+    alignment,
+    keyboard::{self, key},
+    layout::{self, Layout, Node},
+    mouse,
+    overlay,
+    renderer,
+    text,
+    time::{Duration, Instant},
+    touch,
+    widget::{self, Operation, Tree, Widget, operation, tree}, // operate = iced::runtime::widget
+    window,
+};
+use crate::widget::Axis;
+use crate::widget::cell::style::{self, Catalog, Styling};
+use crate::widget::density::{self, Density};
+use crate::widget::shared::{self, DRAG_THRESHOLD, DividerAction};
+use std::any::Any;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::rc::{Rc, Weak};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The orientation of an [`Equal`], selecting the [`Axis`] along which it
+/// distributes its children, being either [`Horizontal`] or [`Vertical`].
+pub trait Orientation: private::Sealed {
+    /// The [`Axis`] along which the children are distributed.
+    const AXIS: Axis;
+}
+
+/// The children are distributed horizontally, and have equal heights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Horizontal;
+
+impl private::Sealed for Horizontal {}
+
+impl Orientation for Horizontal {
+    const AXIS: Axis = Axis::Horizontal;
+}
+
+/// The children are distributed vertically, and have equal widths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vertical;
+
+impl private::Sealed for Vertical {}
+
+impl Orientation for Vertical {
+    const AXIS: Axis = Axis::Vertical;
+}
+
+/// The distribution of the leftover space along the [`Axis`] between the
+/// children of an [`Equal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Justify {
+    /// The children are packed together, and positioned by the alignment.
+    #[default]
+    Start,
+    /// The leftover space is placed between the children, with the first and
+    /// last children at the edges.
+    SpaceBetween,
+    /// Each child has equal space on either side, so the space at the edges
+    /// is half of the space between the children.
+    SpaceAround,
+    /// The space between the children and at the edges is equal.
+    SpaceEvenly,
+}
+
+/// A handle for equalizing the sizes of the children across the [`Axis`] over
+/// several [`Equal`] widgets, such as the label columns of stacked form
+/// sections.
+///
+/// The largest child of all the [`Equal`] widgets joined with
+/// [`Equal::size_group()`] sets the size for every member. The handle is cheap
+/// to clone, and is usually kept in the application state.
+#[derive(Debug, Clone, Default)]
+pub struct SizeGroup {
+    members: Rc<RefCell<Vec<Member>>>,
+}
+
+/// A member of a [`SizeGroup`], with the size of its largest child.
+type Member = (Weak<()>, f32);
+
+impl SizeGroup {
+    /// Creates an empty [`SizeGroup`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The size of the largest child of all the current members.
+    pub fn size(&self) -> f32 {
+        self.members
+            .borrow()
+            .iter()
+            .filter(|(member, _)| member.strong_count() > 0)
+            .map(|(_, size)| *size)
+            .fold(0.0f32, f32::max)
+    }
+
+    /// Records the size of the largest child of the `member`, returning the
+    /// size of the [`SizeGroup`]. Members no longer in use are dropped.
+    fn join(&self, member: &Rc<()>, size: f32) -> f32 {
+        let mut members = self.members.borrow_mut();
+        members.retain(|(member, _)| member.strong_count() > 0);
+        match members
+            .iter_mut()
+            .find(|(existing, _)| existing.as_ptr() == Rc::as_ptr(member))
+        {
+            Some(entry) => entry.1 = size,
+            None => members.push((Rc::downgrade(member), size)),
+        }
+        drop(members);
+        self.size()
+    }
+}
+
+/// The offset of the first text baseline from the top of a widget, which a
+/// widget may report with [`Operation::custom()`] for the baseline alignment of
+/// [`Equal::align_baseline()`].
+///
+/// Otherwise the baseline is estimated from the first text or text input
+/// found in the widget. A text uses the height of its own lines, thus texts
+/// wrapping onto more lines than their line breaks should report their
+/// [`Baseline`]. A text input uses the line height of the default text size of
+/// the renderer, thus text inputs of other sizes should report their
+/// [`Baseline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Baseline(pub f32);
+
+/// The estimated position of the baseline within a line of text, as a ratio of
+/// the line height.
+const BASELINE_RATIO: f32 = 0.75;
+
+/// The duration of opening the drop gap while reordering.
+const GAP_DURATION: Duration = Duration::from_millis(150);
+
+/// The size along the axis of the overflow button.
+const OVERFLOW_BUTTON_SIZE: f32 = 24.0;
+
+/// Boxes the style function of a `class` of the [`Cell`] style catalog.
+fn class_style<'a, Theme>(class: Theme::Class<'a>) -> style::StyleFn<'a, Theme>
+where
+    Theme: Catalog + 'a,
+    Theme::Class<'a>: 'a,
+{
+    Box::new(move |theme: &Theme, status| theme.style(&class, status))
+}
+
+/// The line height of the default text size of the `renderer`.
+fn default_line_height<Renderer>(renderer: &Renderer) -> f32
+where
+    Renderer: text::Renderer,
+{
+    text::LineHeight::default()
+        .to_absolute(renderer.default_size())
+        .0
+}
+
+/// Finds the first text baseline of a widget, estimating the baseline of a
+/// text input from the `line_height` of the default text size when not
+/// reported.
+#[derive(Debug)]
+struct FindBaseline {
+    line_height: f32,
+    baseline: Option<f32>,
+}
+
+impl Operation for FindBaseline {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if self.baseline.is_none() {
+            operate_on_children(self);
+        }
+    }
+
+    fn text_input(
+        &mut self,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        _state: &mut dyn operation::TextInput,
+    ) {
+        // The text of a text input is centred within its padding.
+        if self.baseline.is_none() {
+            let top = bounds.center_y() - self.line_height / 2.0;
+            self.baseline = Some(top + self.line_height * BASELINE_RATIO);
+        }
+    }
+
+    fn text(&mut self, _id: Option<&widget::Id>, bounds: Rectangle, text: &str) {
+        // The text lines share the height of the text, whatever its size.
+        if self.baseline.is_none() {
+            let line_height = bounds.height / text.lines().count().max(1) as f32;
+            self.baseline = Some(bounds.y + line_height * BASELINE_RATIO);
+        }
+    }
+
+    fn custom(&mut self, _id: Option<&widget::Id>, bounds: Rectangle, state: &mut dyn Any) {
+        if self.baseline.is_none()
+            && let Some(Baseline(offset)) = state.downcast_ref::<Baseline>()
+        {
+            self.baseline = Some(bounds.y + offset);
+        }
+    }
+}
+
+/// Widget for a row or column of equal sized entries. Ensures the children are
+/// resized across the [`Axis`].
+///
+/// Usually used through the [`EqualHeightRow`] and [`EqualWidthColumn`]
+/// aliases.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{container};
+/// use iced_experimental_rizzen_yazston::widget::EqualHeightRow;
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     EqualHeightRow::with_children([
+///         container("Left element").into(),
+///         container("Centre element").into(),
+///         container("Right element").into(),
+///     ]).into()
+/// }
+/// ```
+pub struct Equal<'a, A, Message, Theme, Renderer>
+where
+    A: Orientation,
+{
+    axis: PhantomData<A>,
+    spacing: Option<f32>,
+    density: Option<Density>,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: Option<f32>,
+    max_height: Option<f32>,
+    min_width: Option<f32>,
+    min_height: Option<f32>,
+    uniform: bool,
+    wrap: bool,
+    line_spacing: Option<f32>,
+    equal_lines: bool,
+    line_alignment: alignment::Alignment,
+    size_group: Option<SizeGroup>,
+    justify: Justify,
+    align_baseline: Option<fn(&Renderer) -> f32>,
+    divider_width: f32,
+    #[allow(clippy::type_complexity)]
+    on_divider_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    divider_style: Option<style::StyleFn<'a, Theme>>,
+    child_limits: Vec<(f32, f32)>,
+    #[allow(clippy::type_complexity)]
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    overflow_menu: bool,
+    menu_style: Option<style::StyleFn<'a, Theme>>,
+    vertical: alignment::Vertical,
+    horizontal: alignment::Horizontal,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, A, Message, Theme, Renderer> Default for Equal<'a, A, Message, Theme, Renderer>
+where
+    A: Orientation,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, A, Message, Theme, Renderer> Equal<'a, A, Message, Theme, Renderer>
+where
+    A: Orientation,
+    Renderer: renderer::Renderer + 'a,
+{
+    /// Creates a [`Equal`] from an already allocated [`Vec`].
+    pub fn from_vec(children: Vec<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            axis: PhantomData,
+            spacing: None,
+            density: None,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: None,
+            max_height: None,
+            min_width: None,
+            min_height: None,
+            uniform: false,
+            wrap: false,
+            line_spacing: None,
+            equal_lines: false,
+            line_alignment: alignment::Alignment::Start,
+            size_group: None,
+            justify: Justify::Start,
+            align_baseline: None,
+            divider_width: 5.0,
+            on_divider_resize: None,
+            divider_style: None,
+            child_limits: Vec::new(),
+            on_reorder: None,
+            overflow_menu: false,
+            menu_style: None,
+            vertical: alignment::Vertical::Top,
+            horizontal: alignment::Horizontal::Left,
+            children,
+        }
+    }
+
+    /// Creates an empty [`Equal`].
+    pub fn new() -> Self {
+        Self::from_vec(Vec::new())
+    }
+
+    /// Creates a [`Equal`] with the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_vec(Vec::with_capacity(capacity))
+    }
+
+    /// Creates a [`Equal`] with the given elements.
+    pub fn with_children(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let iterator = children.into_iter();
+
+        Self::with_capacity(iterator.size_hint().0).extend(iterator)
+    }
+
+    /// Extends the [`Equal`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+
+    /// Adds an [`Element`] to the [`Equal`].
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        let child = child.into();
+        let child_size = child.as_widget().size_hint();
+        self.width = self.width.enclose(child_size.width);
+        self.height = self.height.enclose(child_size.height);
+        self.children.push(child);
+        self
+    }
+
+    /// Adds an element to the [`Equal`], if `Some`.
+    pub fn push_maybe(
+        self,
+        child: Option<impl Into<Element<'a, Message, Theme, Renderer>>>,
+    ) -> Self {
+        if let Some(child) = child {
+            self.push(child)
+        } else {
+            self
+        }
+    }
+
+    /// Sets the spacing _between_ elements along the [`Axis`].
+    ///
+    /// Custom margins per element do not exist in iced. You should use this
+    /// method instead! While less flexible, it helps you keep spacing between
+    /// elements consistent.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = Some(amount.into().0);
+        self
+    }
+
+    /// Sets the [`Density`] of the [`Equal`], which is inherited by the
+    /// widgets inside the [`Equal`].
+    ///
+    /// Unless set with [`Equal::spacing()`], the spacing of the [`Density`]
+    /// is used.
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = Some(density);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Equal`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Equal`], such as [`Length::Fill`] to stretch
+    /// across the available space.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Equal`], such as [`Length::Fill`] to stretch
+    /// across the available space.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the [`Equal`].
+    pub fn fixed_height(mut self, height: Option<f32>) -> Self {
+        self.height = match height {
+            None => Length::Shrink,
+            Some(value) => Length::Fixed(value),
+        };
+        self
+    }
+
+    /// Sets the width of the [`Equal`].
+    pub fn fixed_width(mut self, width: Option<f32>) -> Self {
+        self.width = match width {
+            None => Length::Shrink,
+            Some(value) => Length::Fixed(value),
+        };
+        self
+    }
+
+    /// Sets the maximum height of the [`Equal`].
+    pub fn max_height(mut self, height: Option<f32>) -> Self {
+        self.max_height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Equal`].
+    pub fn max_width(mut self, width: Option<f32>) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Sets the minimum height of the [`Equal`].
+    pub fn min_height(mut self, height: Option<f32>) -> Self {
+        self.min_height = height;
+        self
+    }
+
+    /// Sets the minimum width of the [`Equal`].
+    pub fn min_width(mut self, width: Option<f32>) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Sets whether the children also have an equal size along the [`Axis`],
+    /// being the size of the largest child, such as for toolbar buttons or tabs
+    /// of equal widths.
+    ///
+    /// The maximum and minimum sizes of the [`Equal`] are still honoured.
+    ///
+    /// Children filling along the [`Axis`] are left out of the largest child,
+    /// and share the space left by the other children by their portions
+    /// instead. When wrapping, they still take up a whole line.
+    pub fn uniform(mut self, uniform: bool) -> Self {
+        self.uniform = uniform;
+        self
+    }
+
+    /// Sets whether the children flow onto new lines when the available size
+    /// along the [`Axis`] runs out, such as for lists of tags.
+    ///
+    /// Each line equalizes the sizes of its own children across the [`Axis`],
+    /// unless [`Equal::equal_lines()`] is set. Children filling along the
+    /// [`Axis`] take up a whole line.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the spacing _between_ the lines of a wrapping [`Equal`].
+    ///
+    /// Unless set, the spacing between the elements is used.
+    pub fn line_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.line_spacing = Some(amount.into().0);
+        self
+    }
+
+    /// Sets whether all the lines of a wrapping [`Equal`] share the size of
+    /// the largest line across the [`Axis`].
+    pub fn equal_lines(mut self, equal_lines: bool) -> Self {
+        self.equal_lines = equal_lines;
+        self
+    }
+
+    /// Sets the alignment of the children within each line of a wrapping
+    /// [`Equal`] along the [`Axis`].
+    pub fn line_alignment(mut self, alignment: impl Into<alignment::Alignment>) -> Self {
+        self.line_alignment = alignment.into();
+        self
+    }
+
+    /// Joins the [`Equal`] to the [`SizeGroup`], so the children have the size
+    /// of the largest child of all the members across the [`Axis`].
+    pub fn size_group(mut self, group: &SizeGroup) -> Self {
+        self.size_group = Some(group.clone());
+        self
+    }
+
+    /// Sets how the leftover space along the [`Axis`] is distributed between
+    /// the children, such as for toolbars and button bars.
+    ///
+    /// Other than [`Justify::Start`], the [`Justify`] mode takes precedence over
+    /// the alignment along the [`Axis`], and the line alignment when wrapping.
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Sets whether the contents of the children are lined up by their first
+    /// text baselines, such as for a label next to a `text_input`.
+    ///
+    /// The children are moved down to the lowest baseline, shrinking to stay
+    /// within the equal size of their line.
+    ///
+    /// Only used along the [`Horizontal`] axis. A [`Vertical`] [`Equal`]
+    /// ignores it, keeping the alignment set by [`Equal::align_x()`].
+    pub fn align_baseline(mut self, align_baseline: bool) -> Self
+    where
+        Renderer: text::Renderer,
+    {
+        self.align_baseline =
+            align_baseline.then_some(default_line_height::<Renderer> as fn(&Renderer) -> f32);
+        self
+    }
+
+    /// Places draggable dividers between adjacent children, such as for a
+    /// sidebar, content and inspector layout. Dragging a divider produces the
+    /// message with the index of the child before the divider, and the new
+    /// size of that child along the [`Axis`].
+    ///
+    /// The dividers are styled by the [`Cell`] style catalog, using the
+    /// ready-made [`Styling::Divider`] class, unless set with
+    /// [`Equal::divider_style()`] or [`Equal::divider_class()`].
+    pub fn dividers(mut self, on_resize: impl Fn(usize, f32) -> Message + 'a) -> Self
+    where
+        Theme: Catalog + 'a,
+        Theme::Class<'a>: From<Styling> + 'a,
+    {
+        self.on_divider_resize = Some(Box::new(on_resize));
+        if self.divider_style.is_none() {
+            self.divider_style = Some(class_style(Styling::Divider(false).into()));
+        }
+        self
+    }
+
+    /// Sets the style of the dividers of the [`Equal`].
+    pub fn divider_style(
+        mut self,
+        style: impl Fn(&Theme, style::Status) -> style::Style + 'a,
+    ) -> Self {
+        self.divider_style = Some(Box::new(style));
+        self
+    }
+
+    /// Sets the style class of the dividers of the [`Equal`].
+    pub fn divider_class(mut self, class: impl Into<Theme::Class<'a>>) -> Self
+    where
+        Theme: Catalog + 'a,
+        Theme::Class<'a>: 'a,
+    {
+        self.divider_style = Some(class_style(class.into()));
+        self
+    }
+
+    /// Sets the width of the dividers of the [`Equal`].
+    pub fn divider_width(mut self, width: f32) -> Self {
+        self.divider_width = width.max(1.0);
+        self
+    }
+
+    /// Sets the minimum size along the [`Axis`] of the child at the `index`,
+    /// when its divider is dragged.
+    pub fn child_min_size(mut self, index: usize, size: f32) -> Self {
+        self.child_limits(index).0 = size.max(0.0);
+        self
+    }
+
+    /// Sets the maximum size along the [`Axis`] of the child at the `index`,
+    /// when its divider is dragged.
+    pub fn child_max_size(mut self, index: usize, size: f32) -> Self {
+        self.child_limits(index).1 = size.max(0.0);
+        self
+    }
+
+    /// Sets the message that will be produced when a child is dragged to a new
+    /// position, such as for tab strips, with the index of the child and the
+    /// index the child is moved to after removing it from its old position.
+    /// Setting this will enable the reordering of the children.
+    ///
+    /// While dragging, a gap opens where the child would be dropped. After
+    /// pressing a child, it may also be moved with the `Alt` and arrow keys,
+    /// until another widget is focused. Focusing the [`Equal`] itself selects
+    /// the first child. Reordering is not available when wrapping.
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Moves the children not fitting within the available size, or the
+    /// maximum size, along the [`Axis`] into an overlay menu, opened by a "…"
+    /// button at the end, such as for responsive toolbars. The children in the
+    /// menu are listed vertically with equal widths.
+    ///
+    /// The button and menu are styled by the [`Cell`] style catalog, using the
+    /// ready-made [`Styling::Label`] class, unless set with
+    /// [`Equal::overflow_menu_style()`] or [`Equal::overflow_menu_class()`].
+    /// The overflow menu is not available when wrapping.
+    ///
+    /// When combined with [`Equal::justify()`], the leftover space is
+    /// distributed between the children remaining in the [`Equal`], with the
+    /// button following the last of them.
+    pub fn overflow_menu(mut self) -> Self
+    where
+        Theme: Catalog + 'a,
+        Theme::Class<'a>: From<Styling> + 'a,
+    {
+        self.overflow_menu = true;
+        if self.menu_style.is_none() {
+            self.menu_style = Some(class_style(Styling::Label.into()));
+        }
+        self
+    }
+
+    /// Sets the style of the overflow button and menu of
+    /// [`Equal::overflow_menu()`].
+    pub fn overflow_menu_style(
+        mut self,
+        style: impl Fn(&Theme, style::Status) -> style::Style + 'a,
+    ) -> Self {
+        self.menu_style = Some(Box::new(style));
+        self
+    }
+
+    /// Sets the style class of the overflow button and menu of
+    /// [`Equal::overflow_menu()`].
+    pub fn overflow_menu_class(mut self, class: impl Into<Theme::Class<'a>>) -> Self
+    where
+        Theme: Catalog + 'a,
+        Theme::Class<'a>: 'a,
+    {
+        self.menu_style = Some(class_style(class.into()));
+        self
+    }
+
+    /// The minimum and maximum sizes of the child at the `index`.
+    fn child_limits(&mut self, index: usize) -> &mut (f32, f32) {
+        if self.child_limits.len() <= index {
+            self.child_limits.resize(index + 1, (0.0, f32::INFINITY));
+        }
+        &mut self.child_limits[index]
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Equal`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Alignment>) -> Self {
+        self.horizontal = alignment::Horizontal::from(alignment.into());
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Equal`].
+    pub fn align_y(mut self, alignment: impl Into<alignment::Alignment>) -> Self {
+        self.vertical = alignment::Vertical::from(alignment.into());
+        self
+    }
+
+    /// Reverse the order of the existing children [`Equal`].
+    pub fn reverse(mut self) -> Self {
+        self.children.reverse();
+        self
+    }
+}
+
+impl<'a, A, Message, Theme, Renderer> Equal<'a, A, Message, Theme, Renderer>
+where
+    A: Orientation,
+{
+    /// The size along the [`Axis`].
+    fn main(size: Size) -> f32 {
+        A::AXIS.main(size)
+    }
+
+    /// The size across the [`Axis`].
+    fn cross(size: Size) -> f32 {
+        A::AXIS.cross(size)
+    }
+
+    /// Combines the sizes along and across the [`Axis`] into a [`Size`].
+    fn pack(main: f32, cross: f32) -> Size {
+        A::AXIS.size(main, cross)
+    }
+
+    /// Joins the [`SizeGroup`], if any, with the size of the largest child
+    /// across the [`Axis`], returning the size to be used by the children.
+    fn group_cross(&self, tree: &mut Tree, largest: f32) -> f32 {
+        let Some(group) = &self.size_group else {
+            return largest;
+        };
+        let state = tree.state.downcast_mut::<State>();
+        let size = group.join(&state.member, largest);
+        state.group_size = Some(size);
+        size
+    }
+
+    /// Distributes the leftover space of each line between the children along
+    /// the [`Axis`], according to the [`Justify`] mode.
+    ///
+    /// When `is_overflowing`, the overflow button appended to the `children`
+    /// keeps its space at the end of the line, and follows the last child.
+    fn justify_lines(
+        &self,
+        children: &mut [Node],
+        lines: &[Range<usize>],
+        size: Size,
+        is_overflowing: bool,
+        spacing: f32,
+    ) {
+        let main_start = Self::main(Size::new(self.padding.left, self.padding.top));
+        let mut content_main =
+            Self::main(size) - Self::main(Size::new(self.padding.x(), self.padding.y()));
+        if is_overflowing {
+            content_main -= spacing + OVERFLOW_BUTTON_SIZE;
+        }
+        let position = |node: &Node| Self::main(Size::new(node.bounds().x, node.bounds().y));
+        for line in lines {
+            let line_children = &mut children[line.clone()];
+            let (Some(first), Some(last)) = (line_children.first(), line_children.last()) else {
+                continue;
+            };
+            let start = position(first);
+            let free = content_main - (position(last) + Self::main(last.size()) - start);
+            if free <= 0.0 {
+                continue;
+            }
+            let count = line_children.len() as f32;
+            let (lead, extra) = match self.justify {
+                Justify::Start => continue,
+                Justify::SpaceBetween if count > 1.0 => (0.0, free / (count - 1.0)),
+                Justify::SpaceBetween => (0.0, 0.0),
+                Justify::SpaceAround => (free / count / 2.0, free / count),
+                Justify::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+            };
+            for (index, child) in line_children.iter_mut().enumerate() {
+                let shift = Self::pack(main_start - start + lead + index as f32 * extra, 0.0);
+                child.translate_mut(Vector::new(shift.width, shift.height));
+            }
+            if is_overflowing {
+                let end =
+                    position(&children[line.end - 1]) + Self::main(children[line.end - 1].size());
+                if let Some(button) = children.last_mut() {
+                    let shift = Self::pack(end + spacing - position(button), 0.0);
+                    button.translate_mut(Vector::new(shift.width, shift.height));
+                }
+            }
+        }
+    }
+
+    /// Creates the equalized node of the `child` at the `position`, moving the
+    /// whole `child` across the [`Axis`] by the baseline `offset`, while
+    /// shrinking it to stay within the `size`.
+    fn shift_baseline(child: &Node, size: Size, position: Size, offset: f32) -> Node {
+        let shift = Self::pack(0.0, offset);
+        Node::with_children(
+            Self::pack(Self::main(size), Self::cross(size) - offset),
+            child.children().to_owned(),
+        )
+        .move_to(
+            Point::new(position.width, position.height) + Vector::new(shift.width, shift.height),
+        )
+    }
+
+    /// The bounds of the dividers, each with the index of the child before the
+    /// divider. Adjacent children on different lines have no divider.
+    fn divider_bounds(&self, state: &State, layout: Layout<'_>) -> Vec<(usize, Rectangle)> {
+        let bounds = layout
+            .children()
+            .take(self.visible(state))
+            .map(|child| child.bounds())
+            .collect::<Vec<Rectangle>>();
+        bounds
+            .windows(2)
+            .enumerate()
+            .filter_map(|(index, pair)| {
+                let (first, second) = (pair[0], pair[1]);
+                let end = Self::main(Size::new(first.x + first.width, first.y + first.height));
+                let start = Self::main(Size::new(second.x, second.y));
+                let cross = Self::cross(Size::new(first.x, first.y));
+                if start < end || Self::cross(Size::new(second.x, second.y)) != cross {
+                    return None;
+                }
+                let position = Self::pack((end + start - self.divider_width) / 2.0, cross);
+                Some((
+                    index,
+                    Rectangle::new(
+                        Point::new(position.width, position.height),
+                        Self::pack(self.divider_width, Self::cross(first.size())),
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    /// The index the child being reordered would be dropped at, being the
+    /// number of other children with their centres before the centre of the
+    /// dragged child.
+    fn reorder_target(&self, state: &State, layout: Layout<'_>, from: usize, moved: f32) -> usize {
+        let centre =
+            |bounds: Rectangle| Self::main(Size::new(bounds.center_x(), bounds.center_y()));
+        let Some(dragged) = layout.children().nth(from) else {
+            return from;
+        };
+        let dragged = centre(dragged.bounds()) + moved;
+        layout
+            .children()
+            .take(self.visible(state))
+            .enumerate()
+            .filter(|(index, child)| *index != from && centre(child.bounds()) < dragged)
+            .count()
+    }
+
+    /// The offset along the [`Axis`] of the child at the `index` while
+    /// reordering, closing the space of the dragged child and opening the
+    /// drop gap.
+    fn reorder_shift(&self, state: &State, layout: Layout<'_>, index: usize) -> f32 {
+        let (Some(from), Some(to)) = (state.reorder_drag, state.reorder_target) else {
+            return 0.0;
+        };
+        let Some(dragged) = layout.children().nth(from) else {
+            return 0.0;
+        };
+        let gap = Self::main(dragged.bounds().size()) + state.spacing;
+        let position = if index > from { index - 1 } else { index };
+        let mut shift = if index > from { -gap } else { 0.0 };
+        if position >= to {
+            shift += gap * state.gap_progress;
+        }
+        shift
+    }
+
+    /// The number of children shown, being those not in the overflow menu.
+    fn visible(&self, state: &State) -> usize {
+        state.overflow_from.unwrap_or(self.children.len())
+    }
+
+    /// The bounds of the overflow button, when children are in the overflow
+    /// menu.
+    fn overflow_bounds(&self, state: &State, layout: Layout<'_>) -> Option<Rectangle> {
+        state.overflow_from?;
+        layout
+            .children()
+            .nth(self.children.len())
+            .map(|button| button.bounds())
+    }
+
+    /// Handles the opening and closing of the overflow menu.
+    fn update_overflow(
+        &self,
+        state: &mut State,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(button) = self.overflow_bounds(state, layout) else {
+            return;
+        };
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if cursor.is_over(button) && !shell.is_event_captured() {
+                state.is_menu_open = !state.is_menu_open;
+                shell.capture_event();
+                shell.request_redraw();
+            } else if state.is_menu_open && !shell.is_event_captured() {
+                // Pressed outside of the menu, as the menu captures its presses.
+                state.is_menu_open = false;
+                shell.request_redraw();
+            }
+        }
+        let is_overflow_hovered = cursor.is_over(button);
+        if is_overflow_hovered != state.is_overflow_hovered {
+            state.is_overflow_hovered = is_overflow_hovered;
+            if !matches!(event, Event::Window(window::Event::RedrawRequested(_))) {
+                shell.request_redraw();
+            }
+        }
+    }
+
+    /// Draws the overflow button with an ellipsis.
+    fn draw_overflow_button(
+        &self,
+        state: &State,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+    ) where
+        Renderer: renderer::Renderer,
+    {
+        const DOT: f32 = 3.0;
+        const GAP: f32 = 2.0;
+        let (Some(menu_style), Some(bounds)) =
+            (&self.menu_style, self.overflow_bounds(state, layout))
+        else {
+            return;
+        };
+        let status = if state.is_menu_open {
+            style::Status::Pressed
+        } else if state.is_overflow_hovered {
+            style::Status::Hovered
+        } else {
+            style::Status::Active
+        };
+        let style = menu_style(theme, status);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            style.background,
+        );
+        let color = style::text_color(&style, renderer_style.text_color);
+        shared::draw_ellipsis(renderer, bounds.center(), DOT, GAP, color);
+    }
+
+    /// Handles the dragging and keyboard moving of the children.
+    fn update_reorder(
+        &self,
+        state: &mut State,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(on_reorder) = &self.on_reorder else {
+            return;
+        };
+        if self.wrap {
+            return;
+        }
+        let main = |point: Point| Self::main(Size::new(point.x, point.y));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // Children may capture the press, such as buttons of a tab strip.
+                state.selected = None;
+                if state.drag.is_none()
+                    && let Some(position) = cursor.position_over(layout.bounds())
+                    && let Some(index) = layout
+                        .children()
+                        .take(self.visible(state))
+                        .position(|child| child.bounds().contains(position))
+                {
+                    state.reorder_press = Some((index, position));
+                    state.selected = Some(index);
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some((index, origin)) = state.reorder_press else {
+                    return;
+                };
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+                if state.reorder_drag.is_none() && origin.distance(position) > DRAG_THRESHOLD {
+                    state.reorder_drag = Some(index);
+                }
+                if state.reorder_drag.is_some() {
+                    state.reorder_moved = main(position) - main(origin);
+                    let target = self.reorder_target(state, layout, index, state.reorder_moved);
+                    if state.reorder_target != Some(target) {
+                        state.reorder_target = Some(target);
+                        state.gap_started = Some(Instant::now());
+                        state.gap_progress = 0.0;
+                    }
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.reorder_press = None;
+                if let Some(from) = state.reorder_drag.take() {
+                    if let Some(to) = state.reorder_target.take()
+                        && to != from
+                        && !matches!(event, Event::Touch(touch::Event::FingerLost { .. }))
+                    {
+                        shell.publish(on_reorder(from, to));
+                        state.selected = Some(to);
+                    }
+                    state.reorder_moved = 0.0;
+                    state.gap_started = None;
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modifiers,
+                ..
+            }) if modifiers.alt() => {
+                if shell.is_event_captured() {
+                    return;
+                }
+                // The children in the overflow menu are not reordered.
+                let visible = self.visible(state);
+                let Some(from) = state.selected.filter(|from| *from < visible) else {
+                    return;
+                };
+                let to = match (A::AXIS, named) {
+                    (Axis::Horizontal, key::Named::ArrowLeft)
+                    | (Axis::Vertical, key::Named::ArrowUp) => from.checked_sub(1),
+                    (Axis::Horizontal, key::Named::ArrowRight)
+                    | (Axis::Vertical, key::Named::ArrowDown) => {
+                        Some(from + 1).filter(|to| *to < visible)
+                    }
+                    _ => return,
+                };
+                if let Some(to) = to {
+                    shell.publish(on_reorder(from, to));
+                    state.selected = Some(to);
+                }
+                shell.capture_event();
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(started) = state.gap_started {
+                    let elapsed = now.saturating_duration_since(started);
+                    if elapsed < GAP_DURATION {
+                        state.gap_progress = elapsed.as_secs_f32() / GAP_DURATION.as_secs_f32();
+                        shell.request_redraw();
+                    } else {
+                        state.gap_started = None;
+                        state.gap_progress = 1.0;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The maximum size along the [`Axis`] of a shrinking [`Equal`], if any.
+    fn max_main(&self) -> Option<f32> {
+        match A::AXIS {
+            Axis::Horizontal => self.max_width.filter(|_| self.width == Length::Shrink),
+            Axis::Vertical => self.max_height.filter(|_| self.height == Length::Shrink),
+        }
+    }
+
+    /// Moves the children not fitting within the available size along the
+    /// [`Axis`] into the overflow menu, and appends the node of the overflow
+    /// button. The children in the menu keep their nodes, though are skipped
+    /// by their indices when handling events and drawing.
+    ///
+    /// Returns the index of the first child in the overflow menu, if any.
+    fn layout_overflow(
+        &self,
+        limits: &layout::Limits,
+        spacing: f32,
+        node_size: &mut Size,
+        children: &mut Vec<Node>,
+    ) -> Option<usize> {
+        let mut available = Self::main(limits.max());
+        if let Some(max_main) = self.max_main() {
+            available = available.min(max_main);
+        }
+        let end = |node: &Node| {
+            let bounds = node.bounds();
+            Self::main(Size::new(bounds.x + bounds.width, bounds.y + bounds.height))
+        };
+        let padding_end = Self::main(Size::new(self.padding.right, self.padding.bottom));
+        if children.last().map_or(0.0, end) + padding_end <= available {
+            return None;
+        }
+        let limit = available - padding_end - spacing - OVERFLOW_BUTTON_SIZE;
+        let visible = children
+            .iter()
+            .take_while(|child| end(child) <= limit)
+            .count();
+        let main = match visible {
+            0 => Self::main(Size::new(self.padding.left, self.padding.top)),
+            _ => end(&children[visible - 1]) + spacing,
+        };
+        let cross = Self::cross(Size::new(self.padding.left, self.padding.top));
+        let padding_cross = Self::cross(Size::new(self.padding.x(), self.padding.y()));
+        let position = Self::pack(main, cross);
+        let position = Point::new(position.width, position.height);
+        children.push(
+            Node::new(Self::pack(
+                OVERFLOW_BUTTON_SIZE,
+                Self::cross(*node_size) - padding_cross,
+            ))
+            .move_to(position),
+        );
+        *node_size = Self::pack(
+            main + OVERFLOW_BUTTON_SIZE + padding_end,
+            Self::cross(*node_size),
+        );
+        Some(visible)
+    }
+
+    /// The line height of the default text size of the renderer, when aligning
+    /// baselines along the [`Horizontal`] axis.
+    fn baseline_line_height(&self) -> Option<fn(&Renderer) -> f32> {
+        self.align_baseline.filter(|_| A::AXIS == Axis::Horizontal)
+    }
+
+    /// The alignment of the children across the [`Axis`].
+    fn cross_alignment(&self) -> alignment::Alignment {
+        match A::AXIS {
+            Axis::Horizontal => self.vertical.into(),
+            Axis::Vertical => self.horizontal.into(),
+        }
+    }
+}
+
+impl<'a, A, Message, Theme, Renderer> Equal<'a, A, Message, Theme, Renderer>
+where
+    A: Orientation,
+    Renderer: renderer::Renderer,
+{
+    /// Lays out the children on a single line, returning the size of the
+    /// [`Equal`], the child nodes and the line.
+    fn layout_line(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+        spacing: f32,
+    ) -> (Size, Vec<Node>, Vec<Range<usize>>) {
+        let cross_alignment = self.cross_alignment();
+        let node = layout::flex::resolve(
+            A::AXIS.flex(),
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            self.padding,
+            spacing,
+            cross_alignment,
+            &self.children,
+            &mut tree.children,
+        );
+        let mut node_size = node.size();
+
+        // For uniform sizing, the children are placed one after the other using
+        // the main size of the largest child. Filling children are not measured,
+        // as their resolved sizes already take up the remaining space, and
+        // instead share the space left by the uniform children.
+        let main_start = Self::main(Size::new(self.padding.left, self.padding.top));
+        let padding_main = Self::main(Size::new(self.padding.x(), self.padding.y()));
+        let fill_factors = self
+            .children
+            .iter()
+            .map(|child| A::AXIS.main(child.as_widget().size()).fill_factor())
+            .collect::<Vec<u16>>();
+        let largest = node
+            .children()
+            .iter()
+            .zip(&fill_factors)
+            .filter(|(_, factor)| **factor == 0)
+            .map(|(child, _)| Self::main(child.size()))
+            .fold(0.0f32, f32::max);
+        let fill_total = fill_factors
+            .iter()
+            .map(|factor| f32::from(*factor))
+            .sum::<f32>();
+        let mut fill_unit = 0.0;
+        if self.uniform && !node.children().is_empty() {
+            let count = node.children().len() as f32;
+            let uniform_count = fill_factors.iter().filter(|factor| **factor == 0).count() as f32;
+            if fill_total > 0.0 {
+                let remaining = Self::main(node_size)
+                    - padding_main
+                    - largest * uniform_count
+                    - spacing * (count - 1.0);
+                fill_unit = remaining.max(0.0) / fill_total;
+            } else {
+                let main = padding_main + largest * count + spacing * (count - 1.0);
+                node_size = Self::pack(main.min(Self::main(limits.max())), Self::cross(node_size));
+            }
+        }
+
+        let padding_cross = Self::cross(Size::new(self.padding.x(), self.padding.y()));
+        let largest_cross = node
+            .children()
+            .iter()
+            .map(|child| Self::cross(child.size()))
+            .fold(0.0f32, f32::max);
+        let lines = std::iter::once(0..node.children().len()).collect::<Vec<_>>();
+        let baselines = self.layout_baselines(tree, renderer, node.children(), &lines);
+        let shifted_cross = node
+            .children()
+            .iter()
+            .zip(&baselines)
+            .map(|(child, baseline)| Self::cross(child.size()) + baseline)
+            .fold(0.0f32, f32::max);
+        let content_cross = (Self::cross(node.size()) - padding_cross)
+            .max(self.group_cross(tree, largest_cross))
+            .max(shifted_cross);
+        node_size = Self::pack(Self::main(node_size), content_cross + padding_cross);
+        let cross_start = Self::cross(Size::new(self.padding.left, self.padding.top));
+        let mut children = Vec::<Node>::new();
+        let mut uniform_position = main_start;
+        for (index, child) in node.children().iter().enumerate() {
+            let (main_position, main_size) = if self.uniform {
+                let size = match fill_factors[index] {
+                    0 => largest,
+                    factor => fill_unit * f32::from(factor),
+                };
+                let position = uniform_position;
+                uniform_position += size + spacing;
+                (position, size)
+            } else {
+                (
+                    Self::main(Size::new(child.bounds().x, child.bounds().y)),
+                    Self::main(child.size()),
+                )
+            };
+            if self.baseline_line_height().is_some() {
+                children.push(Self::shift_baseline(
+                    child,
+                    Self::pack(main_size, content_cross),
+                    Self::pack(main_position, cross_start),
+                    baselines[index],
+                ));
+                continue;
+            }
+            let cross_position = Self::cross(Size::new(child.bounds().x, child.bounds().y));
+            let child_diff = content_cross - Self::cross(child.size());
+            let offset = match cross_alignment {
+                alignment::Alignment::Start => 0.0,
+                alignment::Alignment::Center => child_diff / 2.0,
+                alignment::Alignment::End => child_diff,
+            };
+            let position = Self::pack(main_position, cross_position + offset);
+            children.push(
+                Node::with_children(
+                    Self::pack(main_size, content_cross),
+                    child.children().to_owned(),
+                )
+                .move_to(Point::new(position.width, position.height)),
+            );
+        }
+        (node_size, children, lines)
+    }
+
+    /// Lays out the children flowing onto new lines when the available size
+    /// along the [`Axis`] runs out, returning the size of the [`Equal`], the
+    /// child nodes and the lines.
+    fn layout_wrap(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+        spacing: f32,
+    ) -> (Size, Vec<Node>, Vec<Range<usize>>) {
+        let limits = limits.width(self.width).height(self.height);
+        let padding = Size::new(self.padding.x(), self.padding.y());
+        let child_limits = limits.shrink(self.padding).loose();
+        let nodes = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(child, state)| child.as_widget().layout(state, renderer, &child_limits))
+            .collect::<Vec<Node>>();
+        // Filling children take up a whole line, so are left out of the
+        // uniform size.
+        let fills = self
+            .children
+            .iter()
+            .map(|child| A::AXIS.main(child.as_widget().size()).is_fill())
+            .collect::<Vec<bool>>();
+        let largest = nodes
+            .iter()
+            .zip(&fills)
+            .filter(|(_, fill)| !**fill)
+            .map(|(node, _)| Self::main(node.size()))
+            .fold(0.0f32, f32::max);
+        let uniform = self.uniform;
+        let main_size = |index: usize| {
+            if uniform && !fills[index] {
+                largest
+            } else {
+                Self::main(nodes[index].size())
+            }
+        };
+
+        // The maximum size of a shrinking `Equal` also limits the lines.
+        let mut available = Self::main(child_limits.max());
+        if let Some(max_main) = self.max_main() {
+            available = available.min(max_main - Self::main(padding));
+        }
+
+        // Break the children into lines, each line having at least one child.
+        let mut lines = Vec::<Range<usize>>::new();
+        let mut start = 0;
+        let mut line_main = 0.0f32;
+        for index in 0..nodes.len() {
+            let size = main_size(index);
+            if index > start && line_main + spacing + size > available {
+                lines.push(start..index);
+                start = index;
+            }
+            line_main = if index > start {
+                line_main + spacing + size
+            } else {
+                size
+            };
+        }
+        if start < nodes.len() {
+            lines.push(start..nodes.len());
+        }
+
+        let line_mains = lines
+            .iter()
+            .map(|line| {
+                line.clone().map(main_size).sum::<f32>() + spacing * (line.len() - 1) as f32
+            })
+            .collect::<Vec<f32>>();
+        let mut line_crosses = lines
+            .iter()
+            .map(|line| {
+                nodes[line.clone()]
+                    .iter()
+                    .map(|node| Self::cross(node.size()))
+                    .fold(0.0f32, f32::max)
+            })
+            .collect::<Vec<f32>>();
+        let baselines = self.layout_baselines(tree, renderer, &nodes, &lines);
+        for (line, cross) in lines.iter().zip(line_crosses.iter_mut()) {
+            *cross = nodes[line.clone()]
+                .iter()
+                .zip(&baselines[line.clone()])
+                .map(|(node, baseline)| Self::cross(node.size()) + baseline)
+                .fold(*cross, f32::max);
+        }
+        if self.equal_lines {
+            let tallest = line_crosses.iter().copied().fold(0.0f32, f32::max);
+            line_crosses.iter_mut().for_each(|cross| *cross = tallest);
+        }
+        if self.size_group.is_some() {
+            let largest = line_crosses.iter().copied().fold(0.0f32, f32::max);
+            let group = self.group_cross(tree, largest);
+            line_crosses
+                .iter_mut()
+                .for_each(|cross| *cross = cross.max(group));
+        }
+        let line_spacing = self.line_spacing.unwrap_or(spacing);
+        let content = Self::pack(
+            line_mains.iter().copied().fold(0.0f32, f32::max),
+            line_crosses.iter().sum::<f32>() + line_spacing * lines.len().saturating_sub(1) as f32,
+        );
+        let size = limits.resolve(self.width, self.height, content.expand(self.padding));
+
+        let content_main = Self::main(size) - Self::main(padding);
+        let main_start = Self::main(Size::new(self.padding.left, self.padding.top));
+        let mut cross_position = Self::cross(Size::new(self.padding.left, self.padding.top));
+        let mut children = Vec::<Node>::with_capacity(nodes.len());
+        for ((line, line_main), line_cross) in lines.iter().zip(line_mains).zip(line_crosses) {
+            let free = (content_main - line_main).max(0.0);
+            let mut main_position = main_start
+                + match self.line_alignment {
+                    alignment::Alignment::Start => 0.0,
+                    alignment::Alignment::Center => free / 2.0,
+                    alignment::Alignment::End => free,
+                };
+            for index in line.clone() {
+                let main = main_size(index);
+                let position = Self::pack(main_position, cross_position);
+                children.push(Self::shift_baseline(
+                    &nodes[index],
+                    Self::pack(main, line_cross),
+                    position,
+                    baselines[index],
+                ));
+                main_position += main + spacing;
+            }
+            cross_position += line_cross + line_spacing;
+        }
+        (size, children, lines)
+    }
+
+    /// Finds the first text baselines of the children, returning for each
+    /// child the offset across the [`Axis`] lining up its baseline with the
+    /// lowest baseline of its line.
+    ///
+    /// All offsets are zero, unless aligning baselines along the
+    /// [`Horizontal`] axis.
+    fn layout_baselines(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        nodes: &[Node],
+        lines: &[Range<usize>],
+    ) -> Vec<f32> {
+        let mut offsets = vec![0.0f32; nodes.len()];
+        let Some(line_height) = self.baseline_line_height() else {
+            return offsets;
+        };
+        let line_height = line_height(renderer);
+        let baselines = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(nodes)
+            .map(|((child, state), node)| {
+                let mut operation = FindBaseline {
+                    line_height,
+                    baseline: None,
+                };
+                child
+                    .as_widget()
+                    .operate(state, Layout::new(node), renderer, &mut operation);
+                operation
+                    .baseline
+                    .map(|baseline| baseline - node.bounds().y)
+            })
+            .collect::<Vec<Option<f32>>>();
+        for line in lines {
+            let lowest = baselines[line.clone()]
+                .iter()
+                .flatten()
+                .copied()
+                .fold(0.0f32, f32::max);
+            for index in line.clone() {
+                if let Some(baseline) = baselines[index] {
+                    offsets[index] = lowest - baseline;
+                }
+            }
+        }
+        offsets
+    }
+}
+
+impl<'a, A, Message, Theme, Renderer> FromIterator<Element<'a, Message, Theme, Renderer>>
+    for Equal<'a, A, Message, Theme, Renderer>
+where
+    A: Orientation,
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: 'a,
+{
+    fn from_iter<T: IntoIterator<Item = Element<'a, Message, Theme, Renderer>>>(iter: T) -> Self {
+        Self::with_children(iter)
+    }
+}
+
+impl<'a, A, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Equal<'a, A, Message, Theme, Renderer>
+where
+    A: Orientation,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> Node {
+        let density = self.density.unwrap_or_else(Density::current);
+        let mut spacing = self.spacing.unwrap_or(density.spacing);
+        if self.on_divider_resize.is_some() {
+            spacing += self.divider_width;
+        }
+        let state = tree.state.downcast_mut::<State>();
+        state.density = density;
+        state.spacing = spacing;
+        let (mut node_size, mut children, mut lines) = density::scope(self.density, || {
+            if self.wrap {
+                self.layout_wrap(tree, renderer, limits, spacing)
+            } else {
+                self.layout_line(tree, renderer, limits, spacing)
+            }
+        });
+        let overflow_from = if self.overflow_menu && !self.wrap {
+            // The children are squeezed into the limits, thus are laid out again
+            // at their own sizes to find those not fitting.
+            let relaxed = layout::Limits::new(
+                limits.min(),
+                Self::pack(f32::INFINITY, Self::cross(limits.max())),
+            );
+            let (mut full_size, mut full_children, _) = density::scope(self.density, || {
+                self.layout_line(tree, renderer, &relaxed, spacing)
+            });
+            let overflow_from =
+                self.layout_overflow(limits, spacing, &mut full_size, &mut full_children);
+            if overflow_from.is_some() {
+                node_size = full_size;
+                children = full_children;
+            }
+            overflow_from
+        } else {
+            None
+        };
+        if let Some(visible) = overflow_from {
+            lines = std::iter::once(0..visible).collect();
+        }
+        let state = tree.state.downcast_mut::<State>();
+        state.overflow_from = overflow_from;
+        state.is_menu_open &= overflow_from.is_some();
+        let mut width = node_size.width;
+        let mut width_diff = 0.0f32;
+        if self.width == Length::Shrink {
+            if let Some(max_width) = self.max_width {
+                width = width.min(max_width);
+            }
+            if let Some(min_width) = self.min_width {
+                width = width.max(min_width);
+                match self.horizontal {
+                    alignment::Horizontal::Center => width_diff = (min_width - width) / 2.0,
+                    alignment::Horizontal::Right => width_diff = min_width - width,
+                    _ => {}
+                }
+            }
+        }
+        let mut height = node_size.height;
+        let mut height_diff = 0.0f32;
+        if self.height == Length::Shrink {
+            if let Some(max_height) = self.max_height {
+                height = height.min(max_height);
+            }
+            if let Some(min_height) = self.min_height {
+                height = height.max(min_height);
+                match self.vertical {
+                    alignment::Vertical::Center => height_diff = (min_height - height) / 2.0,
+                    alignment::Vertical::Bottom => height_diff = min_height - height,
+                    _ => {}
+                }
+            }
+        }
+        let size = Size::new(width, height);
+        let mut children = children
+            .into_iter()
+            .map(|child| child.translate(Vector::new(width_diff, height_diff)))
+            .collect::<Vec<Node>>();
+        self.justify_lines(
+            &mut children,
+            &lines,
+            size,
+            overflow_from.is_some(),
+            spacing,
+        );
+        Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if self.on_reorder.is_some() && !self.wrap {
+            let state = tree.state.downcast_mut::<State>();
+            let count = self.visible(state);
+            operation.focusable(
+                None,
+                layout.bounds(),
+                &mut Selection {
+                    selected: &mut state.selected,
+                    count,
+                },
+            );
+        }
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        // Another member of the size group has changed the size of the group.
+        if let Some(group) = &self.size_group
+            && let Event::Window(window::Event::RedrawRequested(_)) = event
+            && tree.state.downcast_ref::<State>().group_size != Some(group.size())
+        {
+            shell.invalidate_layout();
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let is_reordering = state.reorder_drag.is_some()
+            && !matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                    | Event::Touch(touch::Event::FingerLifted { .. })
+                    | Event::Touch(touch::Event::FingerLost { .. })
+            );
+        let visible = self.visible(state);
+        if state.drag.is_none() && !is_reordering {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .take(visible)
+                .for_each(|((child, state), layout)| {
+                    child.as_widget_mut().update(
+                        state, event, layout, cursor, renderer, clipboard, shell, viewport,
+                    )
+                });
+        }
+        self.update_reorder(state, event, layout, cursor, shell);
+        self.update_overflow(state, event, layout, cursor, shell);
+        let Some(on_resize) = &self.on_divider_resize else {
+            return;
+        };
+        let dividers = self.divider_bounds(state, layout);
+        let main = |point: Point| Self::main(Size::new(point.x, point.y));
+
+        match shared::divider_action(event) {
+            Some(DividerAction::Press) => {
+                if shell.is_event_captured() {
+                    return;
+                }
+                if let Some(position) = cursor.position()
+                    && let Some((index, _)) = dividers
+                        .iter()
+                        .find(|(_, bounds)| bounds.contains(position))
+                    && let Some(child) = layout.children().nth(*index)
+                {
+                    state.drag = Some((*index, main(position), Self::main(child.bounds().size())));
+                    shell.capture_event();
+                }
+            }
+            Some(DividerAction::Move) => {
+                if let Some((index, origin, size)) = state.drag
+                    && let Some(position) = cursor.position()
+                {
+                    let (min, max) = self
+                        .child_limits
+                        .get(index)
+                        .copied()
+                        .unwrap_or((0.0, f32::INFINITY));
+                    let size = (size + main(position) - origin).clamp(min, max.max(min));
+                    shell.publish(on_resize(index, size));
+                    shell.capture_event();
+                }
+            }
+            Some(DividerAction::Release) if state.drag.is_some() => {
+                state.drag = None;
+                shell.capture_event();
+            }
+            _ => {}
+        }
+
+        let hovered_divider = state.drag.map(|(index, _, _)| index).or_else(|| {
+            dividers
+                .iter()
+                .find(|(_, bounds)| cursor.is_over(*bounds))
+                .map(|(index, _)| *index)
+        });
+        shared::hover_divider(&mut state.hovered_divider, hovered_divider, event, shell);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if state.reorder_drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+        if self
+            .overflow_bounds(state, layout)
+            .is_some_and(|button| cursor.is_over(button))
+        {
+            return mouse::Interaction::Pointer;
+        }
+        if self.on_divider_resize.is_some()
+            && (state.drag.is_some()
+                || self
+                    .divider_bounds(state, layout)
+                    .iter()
+                    .any(|(_, bounds)| cursor.is_over(*bounds)))
+        {
+            return shared::divider_interaction(A::AXIS);
+        }
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .take(self.visible(state))
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let equal_state = tree.state.downcast_ref::<State>();
+        if let Some(clipped_viewport) = layout.bounds().intersection(viewport) {
+            for (index, ((child, state), child_layout)) in self
+                .children
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                if equal_state.reorder_drag == Some(index)
+                    || equal_state
+                        .overflow_from
+                        .is_some_and(|visible| index >= visible)
+                {
+                    continue;
+                }
+                let shift = Self::pack(self.reorder_shift(equal_state, layout, index), 0.0);
+                renderer.with_translation(Vector::new(shift.width, shift.height), |renderer| {
+                    child.as_widget().draw(
+                        state,
+                        renderer,
+                        theme,
+                        style,
+                        child_layout,
+                        cursor,
+                        &clipped_viewport,
+                    );
+                });
+            }
+
+            self.draw_overflow_button(equal_state, renderer, theme, style, layout);
+
+            // The dragged child follows the pointer above the other children.
+            if let Some(from) = equal_state.reorder_drag
+                && let (Some(child), Some(state), Some(child_layout)) = (
+                    self.children.get(from),
+                    tree.children.get(from),
+                    layout.children().nth(from),
+                )
+            {
+                let shift = Self::pack(equal_state.reorder_moved, 0.0);
+                renderer.with_layer(clipped_viewport, |renderer| {
+                    renderer.with_translation(Vector::new(shift.width, shift.height), |renderer| {
+                        child.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            style,
+                            child_layout,
+                            cursor,
+                            &clipped_viewport,
+                        );
+                    });
+                });
+            }
+
+            if let (Some(divider_style), Some(_)) = (&self.divider_style, &self.on_divider_resize) {
+                for (index, bounds) in self.divider_bounds(equal_state, layout) {
+                    let is_hovered = equal_state.hovered_divider == Some(index);
+                    shared::draw_divider(renderer, bounds, is_hovered, |status| {
+                        divider_style(theme, status)
+                    });
+                }
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+        let visible = self.visible(state);
+        let button = state
+            .overflow_from
+            .and_then(|_| layout.children().nth(self.children.len()))
+            .map(|button| button.bounds() + translation);
+        let (shown, hidden) = self.children.split_at_mut(visible);
+        let (shown_trees, hidden_trees) = children.split_at_mut(visible);
+        let mut overlays = shown
+            .iter_mut()
+            .zip(shown_trees)
+            .zip(layout.children())
+            .filter_map(|((child, state), layout)| {
+                child
+                    .as_widget_mut()
+                    .overlay(state, layout, renderer, viewport, translation)
+            })
+            .collect::<Vec<_>>();
+        if let (Some(style), Some(button)) = (&self.menu_style, button)
+            && state.is_menu_open
+        {
+            overlays.push(overlay::Element::new(Box::new(OverflowMenu {
+                children: hidden,
+                trees: hidden_trees,
+                is_open: &mut state.is_menu_open,
+                button,
+                density: state.density,
+                style: style.as_ref(),
+            })));
+        }
+        match overlays.len() {
+            0 => None,
+            1 => overlays.pop(),
+            _ => Some(overlay::Group::with_children(overlays).overlay()),
+        }
+    }
+}
+
+/// Just a smaller persistent state for the `Tree`.
+#[derive(Debug, Default)]
+struct State {
+    /// Identifies the [`Equal`] within its [`SizeGroup`].
+    member: Rc<()>,
+    /// The size of the [`SizeGroup`] used by the last layout.
+    group_size: Option<f32>,
+    /// The divider being dragged, with the index of the child before the
+    /// divider, and the pointer position and child size at the start.
+    drag: Option<(usize, f32, f32)>,
+    /// The index of the child before the hovered divider.
+    hovered_divider: Option<usize>,
+    /// The inherited [`Density`] used by the last layout.
+    density: Density,
+    /// The spacing between the children used by the last layout.
+    spacing: f32,
+    /// The child pressed for reordering, with the pointer position.
+    reorder_press: Option<(usize, Point)>,
+    /// The child being dragged for reordering.
+    reorder_drag: Option<usize>,
+    /// The distance the dragged child has moved along the [`Axis`].
+    reorder_moved: f32,
+    /// The index the dragged child would be dropped at.
+    reorder_target: Option<usize>,
+    /// The start of opening the drop gap at the target.
+    gap_started: Option<Instant>,
+    /// The opening of the drop gap, from `0.0` to `1.0`.
+    gap_progress: f32,
+    /// The child to be moved with the keyboard.
+    selected: Option<usize>,
+    /// The first child in the overflow menu, if any.
+    overflow_from: Option<usize>,
+    /// Whether the overflow menu is open.
+    is_menu_open: bool,
+    /// Whether the overflow button is hovered.
+    is_overflow_hovered: bool,
+}
+
+/// The child selected for moving with the keyboard, taking part in the focus
+/// of the widgets, so the selection is dropped when another widget is focused.
+struct Selection<'a> {
+    selected: &'a mut Option<usize>,
+    count: usize,
+}
+
+impl operation::Focusable for Selection<'_> {
+    fn is_focused(&self) -> bool {
+        self.selected.is_some()
+    }
+
+    fn focus(&mut self) {
+        if self.selected.is_none() && self.count > 0 {
+            *self.selected = Some(0);
+        }
+    }
+
+    fn unfocus(&mut self) {
+        *self.selected = None;
+    }
+}
+
+/// The overflow menu, listing the children not fitting in the [`Equal`]
+/// vertically with equal widths.
+struct OverflowMenu<'a, 'b, Message, Theme, Renderer> {
+    children: &'b mut [Element<'a, Message, Theme, Renderer>],
+    trees: &'b mut [Tree],
+    is_open: &'b mut bool,
+    button: Rectangle,
+    density: Density,
+    style: &'b dyn Fn(&Theme, style::Status) -> style::Style,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for OverflowMenu<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+        let nodes = density::scope(Some(self.density), || {
+            self.children
+                .iter()
+                .zip(self.trees.iter_mut())
+                .map(|(child, tree)| child.as_widget().layout(tree, renderer, &limits))
+                .collect::<Vec<Node>>()
+        });
+        let width = nodes
+            .iter()
+            .map(|node| node.size().width)
+            .fold(self.button.width, f32::max);
+        let mut y = 0.0f32;
+        let children = nodes
+            .into_iter()
+            .map(|node| {
+                let child = Node::with_children(
+                    Size::new(width, node.size().height),
+                    node.children().to_owned(),
+                )
+                .move_to(Point::new(0.0, y));
+                y += node.size().height;
+                child
+            })
+            .collect::<Vec<Node>>();
+        let size = Size::new(width, y);
+
+        // The menu is aligned to the right edge of the button.
+        let x = self.button.x + self.button.width - size.width;
+        Node::with_children(size, children).translate(shared::overlay_offset(
+            self.button,
+            x,
+            size,
+            bounds,
+        ))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = (self.style)(theme, style::Status::Active);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: style.border,
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            style.background,
+        );
+        let renderer_style = renderer::Style {
+            text_color: style::text_color(&style, renderer_style.text_color),
+        };
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+        {
+            child.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer_style,
+                layout,
+                cursor,
+                &layout.bounds(),
+            );
+        }
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(self.trees.iter_mut())
+            .zip(layout.children())
+        {
+            child.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell, &bounds,
+            );
+        }
+        match event {
+            // The presses within the menu are kept from closing the menu.
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(bounds) =>
+            {
+                shell.capture_event();
+            }
+            // The menu closes after a child has been chosen.
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if cursor.is_over(bounds) =>
+            {
+                *self.is_open = false;
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        self.children
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, &bounds, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl<'a, A, Message, Theme, Renderer> From<Equal<'a, A, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    A: Orientation + 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(equal: Equal<'a, A, Message, Theme, Renderer>) -> Self {
+        Self::new(equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Theme;
+    use crate::core::clipboard;
+    use iced_widget::{Container, Space};
+
+    type Row = Equal<'static, Horizontal, (), Theme, ()>;
+
+    /// A row of five children, each 50 wide, without spacing.
+    fn row() -> Row {
+        Row::with_children((0..5).map(|_| Container::new(Space::new(50.0, 10.0)).into()))
+            .spacing(0.0)
+    }
+
+    /// Lays out the `equal` within the `max_width`.
+    fn layout(equal: &Row, max_width: f32) -> (Tree, Node) {
+        let mut tree = Tree::new(equal as &dyn Widget<(), Theme, ()>);
+        let limits = layout::Limits::new(Size::ZERO, Size::new(max_width, 100.0));
+        let node = equal.layout(&mut tree, &(), &limits);
+        (tree, node)
+    }
+
+    #[test]
+    fn overflow_keeps_the_nodes_of_hidden_children() {
+        let equal = row().overflow_menu();
+        let (tree, node) = layout(&equal, 200.0);
+        let state = tree.state.downcast_ref::<State>();
+        assert_eq!(state.overflow_from, Some(3));
+        assert_eq!(node.children().len(), 6);
+        assert_eq!(node.children()[4].size().width, 50.0);
+        assert_eq!(node.children()[4].children().len(), 1);
+        let button = node.children()[5].bounds();
+        assert_eq!(button.x, 150.0);
+        assert_eq!(node.size().width, 150.0 + OVERFLOW_BUTTON_SIZE);
+    }
+
+    #[test]
+    fn overflow_button_follows_justified_children() {
+        let equal = row()
+            .overflow_menu()
+            .justify(Justify::SpaceBetween)
+            .min_width(Some(200.0));
+        let (_, node) = layout(&equal, 200.0);
+        let children = node.children();
+        assert_eq!(children[2].bounds().x + 50.0, 200.0 - OVERFLOW_BUTTON_SIZE);
+        assert_eq!(children[5].bounds().x, 200.0 - OVERFLOW_BUTTON_SIZE);
+    }
+
+    #[test]
+    fn justify_distributes_the_leftover_space() {
+        let positions = |justify| {
+            let equal =
+                Row::with_children((0..3).map(|_| Container::new(Space::new(50.0, 10.0)).into()))
+                    .spacing(0.0)
+                    .width(300.0)
+                    .justify(justify);
+            let (_, node) = layout(&equal, 300.0);
+            node.children()
+                .iter()
+                .map(|child| child.bounds().x)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(positions(Justify::Start), [0.0, 50.0, 100.0]);
+        assert_eq!(positions(Justify::SpaceBetween), [0.0, 125.0, 250.0]);
+        assert_eq!(positions(Justify::SpaceAround), [25.0, 125.0, 225.0]);
+        assert_eq!(positions(Justify::SpaceEvenly), [37.5, 125.0, 212.5]);
+    }
+
+    #[test]
+    fn size_group_shares_the_largest_child() {
+        let group = SizeGroup::new();
+        let column = |height| {
+            Row::with_children([Container::new(Space::new(50.0, height)).into()]).size_group(&group)
+        };
+        let short = column(10.0);
+        let tall = column(30.0);
+        let (mut short_tree, _) = layout(&short, 200.0);
+        let (tall_tree, _) = layout(&tall, 200.0);
+        assert_eq!(group.size(), 30.0);
+        let node = short.layout(
+            &mut short_tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+        assert_eq!(node.children()[0].size().height, 30.0);
+        drop(tall_tree);
+        assert_eq!(group.size(), 10.0);
+    }
+
+    #[test]
+    fn hidden_children_have_no_mouse_interaction() {
+        let equal = row().overflow_menu();
+        let (tree, node) = layout(&equal, 200.0);
+        let cursor = mouse::Cursor::Available(Point::new(225.0, 5.0));
+        let viewport = Rectangle::with_size(Size::new(200.0, 100.0));
+        let interaction =
+            equal.mouse_interaction(&tree, Layout::new(&node), cursor, &viewport, &());
+        assert_eq!(interaction, mouse::Interaction::default());
+    }
+
+    #[test]
+    fn pressing_the_overflow_button_selects_no_child() {
+        let mut equal = row().overflow_menu().on_reorder(|_, _| ());
+        let (mut tree, node) = layout(&equal, 200.0);
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let button = node.children()[5].bounds().center();
+        equal.update(
+            &mut tree,
+            &Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            mouse::Cursor::Available(button),
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::new(200.0, 100.0)),
+        );
+        let state = tree.state.downcast_ref::<State>();
+        assert_eq!(state.selected, None);
+        assert_eq!(state.reorder_press, None);
+        assert!(state.is_menu_open);
+    }
+
+    #[test]
+    fn reorder_target_stays_within_shown_children() {
+        let equal = row().overflow_menu().on_reorder(|_, _| ());
+        let (tree, node) = layout(&equal, 200.0);
+        let state = tree.state.downcast_ref::<State>();
+        let target = equal.reorder_target(state, Layout::new(&node), 0, 1000.0);
+        assert_eq!(target, 2);
+    }
+
+    #[test]
+    fn focusing_selects_only_shown_children() {
+        let mut selected = None;
+        let mut selection = Selection {
+            selected: &mut selected,
+            count: 0,
+        };
+        operation::Focusable::focus(&mut selection);
+        assert_eq!(selected, None);
+        let mut selection = Selection {
+            selected: &mut selected,
+            count: 3,
+        };
+        operation::Focusable::focus(&mut selection);
+        assert_eq!(selected, Some(0));
+        let mut selection = Selection {
+            selected: &mut selected,
+            count: 3,
+        };
+        operation::Focusable::unfocus(&mut selection);
+        assert_eq!(selected, None);
+    }
+}
@@ -13,6 +13,8 @@
 //!
 //! * Supports padding around the entire widget.
 //!
+//! * Supports a [`Density`] inherited by the widgets inside the grid.
+//!
 //! # Example
 //! ```no_run
 //! # mod iced { pub mod widget { pub use iced_widget::*; } }
@@ -57,6 +59,7 @@ use crate::core::{
     renderer,
     widget::{Operation, Tree, Widget}, // operate = iced::runtime::widget
 };
+use crate::widget::density::{self, Density};
 use iced_widget::{Space, core::Padding};
 
 /// A container that distributes its contents in a grid of variable column
@@ -89,7 +92,8 @@ use iced_widget::{Space, core::Padding};
 /// }
 /// ```
 pub struct Grid<'a, Message, Theme, Renderer> {
-    spacing: f32,
+    spacing: Option<f32>,
+    density: Option<Density>,
     padding: Padding,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
     column_widths: Vec<f32>,
@@ -123,7 +127,8 @@ where
         let mut actual = children;
         actual.truncate(column_widths.len() * row_heights.len());
         Self {
-            spacing: 0.0,
+            spacing: None,
+            density: None,
             padding: Padding::ZERO,
             children: actual,
             column_widths,
@@ -134,7 +139,8 @@ where
     /// Creates an empty [`Grid`].
     pub fn new() -> Self {
         Self {
-            spacing: 0.0,
+            spacing: None,
+            density: None,
             padding: Padding::ZERO,
             children: Vec::new(),
             column_widths: Vec::new(),
@@ -145,7 +151,8 @@ where
     /// Creates an empty [`Grid`] with the given capacity.
     pub fn with_capacity(columns: usize, rows: usize) -> Self {
         Self {
-            spacing: 0.0,
+            spacing: None,
+            density: None,
             padding: Padding::ZERO,
             children: Vec::with_capacity(rows * columns),
             column_widths: Vec::with_capacity(columns),
@@ -196,7 +203,17 @@ where
     /// method instead! While less flexible, it helps you keep spacing between
     /// elements consistent.
     pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
-        self.spacing = amount.into().0;
+        self.spacing = Some(amount.into().0);
+        self
+    }
+
+    /// Sets the [`Density`] of the [`Grid`], which is inherited by the widgets
+    /// inside the [`Grid`].
+    ///
+    /// Unless set with [`Grid::spacing()`], the spacing of the [`Density`] is
+    /// used.
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = Some(density);
         self
     }
 
@@ -231,13 +248,15 @@ where
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, _limits: &layout::Limits) -> Node {
         // The layout of the children is done in top to down rows of left to right
         // ordering. i.e. Latin scripts.
+        let density = self.density.unwrap_or_else(Density::current);
+        let spacing = self.spacing.unwrap_or(density.spacing);
 
         // Obtain the column positions of the cells within the rows.
         let mut columns = Vec::<(f32, f32)>::new();
         let mut grid_width = self.padding.left;
         for (index, value) in self.column_widths.iter().enumerate() {
             if index > 0 {
-                grid_width += self.spacing;
+                grid_width += spacing;
             }
             columns.push((grid_width, *value));
             grid_width += value;
@@ -250,18 +269,20 @@ where
         let mut grid_height = self.padding.top;
         for (row, height) in self.row_heights.iter().enumerate() {
             if row > 0 {
-                grid_height += self.spacing;
+                grid_height += spacing;
             }
             for (x, width) in &columns {
                 let size = Size {
                     width: *width,
                     height: *height,
                 };
-                let node = self.children[index].as_widget().layout(
-                    &mut tree.children[index],
-                    renderer,
-                    &Limits::new(Size::ZERO, size),
-                );
+                let node = density::scope(self.density, || {
+                    self.children[index].as_widget().layout(
+                        &mut tree.children[index],
+                        renderer,
+                        &Limits::new(Size::ZERO, size),
+                    )
+                });
                 let children = node.children();
                 let mut child: Node = if children.is_empty() {
                     Node::new(size)
@@ -1,5 +1,8 @@
 //! Scrollables let users navigate an endless amount of content with a scrollbar.
 //!
+//! The widths of the scrollbars follow the inherited [`Density`], unless set
+//! with [`Scrollbar::width()`] and [`Scrollbar::scroller_width()`].
+//!
 //! # Example
 //! ```no_run
 //! # mod iced { pub mod widget { pub use iced_widget::*; } }
@@ -38,6 +41,7 @@ use crate::core::{
 };
 use crate::runtime::Action;
 use crate::runtime::task::{self, Task};
+use crate::widget::density::{self, Density};
 
 pub use operation::scrollable::{AbsoluteOffset, RelativeOffset};
 
@@ -73,6 +77,7 @@ where
     width: Length,
     height: Length,
     direction: Direction,
+    density: Option<Density>,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
     class: Theme::Class<'a>,
@@ -99,6 +104,7 @@ where
             width: Length::Shrink,
             height: Length::Shrink,
             direction: direction.into(),
+            density: None,
             content: content.into(),
             on_scroll: None,
             class: Theme::default(),
@@ -142,6 +148,13 @@ where
         self.validate()
     }
 
+    /// Sets the [`Density`] of the [`Scrollable`], which is inherited by the
+    /// widgets inside the [`Scrollable`].
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = Some(density);
+        self
+    }
+
     /// Sets the [`Id`] of the [`Scrollable`].
     pub fn id(mut self, id: impl Into<Id>) -> Self {
         self.id = Some(id.into());
@@ -304,6 +317,21 @@ impl Direction {
             align(vertical_alignment, delta.y),
         )
     }
+
+    /// Applies the [`Density`] to the scrollbars without explicit widths.
+    fn with_density(self, density: &Density) -> Self {
+        match self {
+            Self::Vertical(scrollbar) => Self::Vertical(scrollbar.with_density(density)),
+            Self::Horizontal(scrollbar) => Self::Horizontal(scrollbar.with_density(density)),
+            Self::Both {
+                vertical,
+                horizontal,
+            } => Self::Both {
+                vertical: vertical.with_density(density),
+                horizontal: horizontal.with_density(density),
+            },
+        }
+    }
 }
 
 impl Default for Direction {
@@ -320,16 +348,18 @@ pub struct Scrollbar {
     scroller_width: f32,
     alignment: Anchor,
     spacing: Option<f32>,
+    is_sized: bool,
 }
 
 impl Default for Scrollbar {
     fn default() -> Self {
         Self {
-            width: 10.0,
+            width: Density::COMFORTABLE.scrollbar_width,
             margin: 0.0,
-            scroller_width: 10.0,
+            scroller_width: Density::COMFORTABLE.scrollbar_width,
             alignment: Anchor::Start,
             spacing: None,
+            is_sized: false,
         }
     }
 }
@@ -343,6 +373,7 @@ impl Scrollbar {
     /// Sets the scrollbar width of the [`Scrollbar`] .
     pub fn width(mut self, width: impl Into<Pixels>) -> Self {
         self.width = width.into().0.max(0.0);
+        self.is_sized = true;
         self
     }
 
@@ -355,6 +386,7 @@ impl Scrollbar {
     /// Sets the scroller width of the [`Scrollbar`] .
     pub fn scroller_width(mut self, scroller_width: impl Into<Pixels>) -> Self {
         self.scroller_width = scroller_width.into().0.max(0.0);
+        self.is_sized = true;
         self
     }
 
@@ -364,6 +396,19 @@ impl Scrollbar {
         self
     }
 
+    /// Applies the scrollbar width of the [`Density`], unless a width is set.
+    fn with_density(self, density: &Density) -> Self {
+        if self.is_sized {
+            self
+        } else {
+            Self {
+                width: density.scrollbar_width,
+                scroller_width: density.scrollbar_width,
+                ..self
+            }
+        }
+    }
+
     /// Sets whether the [`Scrollbar`] should be embedded in the [`Scrollable`], using
     /// the given spacing between itself and the contents.
     ///
@@ -421,7 +466,13 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (limit_x, limit_y, padding_x, padding_y) = match self.direction {
+        // The inherited density is only available during layout, so the direction
+        // with the resolved scrollbar widths is kept for the other methods.
+        let direction = self
+            .direction
+            .with_density(&self.density.unwrap_or_else(Density::current));
+        tree.state.downcast_mut::<State>().direction = Some(direction);
+        let (limit_x, limit_y, padding_x, padding_y) = match direction {
             Direction::Both {
                 vertical,
                 horizontal,
@@ -472,10 +523,11 @@ where
             Size::new(limits.min().width, limits.min().height),
             Size::new(limit_x, limit_y),
         );
-        let content =
+        let content = density::scope(self.density, || {
             self.content
                 .as_widget()
-                .layout(&mut tree.children[0], renderer, &content_limits);
+                .layout(&mut tree.children[0], renderer, &content_limits)
+        });
         let content_bounds = content.bounds();
 
         // Both directions of content are smaller than or equal to widget limits.
@@ -578,13 +630,14 @@ where
         operation: &mut dyn Operation,
     ) {
         let state = tree.state.downcast_mut::<State>();
+        let direction = state.direction.unwrap_or(self.direction);
 
         let bounds = layout.bounds();
         let viewport_layout = layout.children().next().unwrap();
         let viewport_bounds = viewport_layout.bounds();
         let content_layout = viewport_layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let translation = state.translation(self.direction, viewport_bounds, content_bounds);
+        let translation = state.translation(direction, viewport_bounds, content_bounds);
 
         operation.scrollable(
             self.id.as_ref().map(|id| &id.0),
@@ -620,6 +673,7 @@ where
         _viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_mut::<State>();
+        let direction = state.direction.unwrap_or(self.direction);
         let bounds = layout.bounds();
         let cursor_over_scrollable = cursor.position_over(bounds);
 
@@ -628,13 +682,7 @@ where
         let content_layout = viewport_layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
 
-        let scrollbars = Scrollbars::new(
-            state,
-            self.direction,
-            bounds,
-            viewport_bounds,
-            content_bounds,
-        );
+        let scrollbars = Scrollbars::new(state, direction, bounds, viewport_bounds, content_bounds);
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) = scrollbars.is_mouse_over(cursor);
 
@@ -792,11 +840,7 @@ where
                     {
                         mouse::Cursor::Available(
                             cursor_position
-                                + state.translation(
-                                    self.direction,
-                                    viewport_bounds,
-                                    content_bounds,
-                                ),
+                                + state.translation(direction, viewport_bounds, content_bounds),
                         )
                     }
                     _ => mouse::Cursor::Unavailable,
@@ -804,8 +848,7 @@ where
 
                 let had_input_method = shell.input_method().is_enabled();
 
-                let translation =
-                    state.translation(self.direction, viewport_bounds, content_bounds);
+                let translation = state.translation(direction, viewport_bounds, content_bounds);
 
                 self.content.as_widget_mut().update(
                     &mut tree.children[0],
@@ -882,7 +925,7 @@ where
                         mouse::ScrollDelta::Pixels { x, y } => -Vector::new(x, y),
                     };
 
-                    state.scroll(self.direction.align(delta), viewport_bounds, content_bounds);
+                    state.scroll(direction.align(delta), viewport_bounds, content_bounds);
 
                     let has_scrolled = notify_scroll(
                         state,
@@ -922,7 +965,7 @@ where
                                 );
 
                                 state.scroll(
-                                    self.direction.align(delta),
+                                    direction.align(delta),
                                     viewport_bounds,
                                     content_bounds,
                                 );
@@ -1005,6 +1048,7 @@ where
         viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State>();
+        let direction = state.direction.unwrap_or(self.direction);
         let bounds = layout.bounds();
         let viewport_layout = layout.children().next().unwrap();
         let viewport_bounds = viewport_layout.bounds();
@@ -1015,18 +1059,12 @@ where
             return;
         };
 
-        let scrollbars = Scrollbars::new(
-            state,
-            self.direction,
-            bounds,
-            viewport_bounds,
-            content_bounds,
-        );
+        let scrollbars = Scrollbars::new(state, direction, bounds, viewport_bounds, content_bounds);
 
         let cursor_over_scrollable = cursor.position_over(bounds);
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) = scrollbars.is_mouse_over(cursor);
 
-        let translation = state.translation(self.direction, viewport_bounds, content_bounds);
+        let translation = state.translation(direction, viewport_bounds, content_bounds);
 
         let cursor = match cursor_over_scrollable {
             Some(cursor_position) if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) => {
@@ -1169,6 +1207,7 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<State>();
+        let direction = state.direction.unwrap_or(self.direction);
         let bounds = layout.bounds();
         let cursor_over_scrollable = cursor.position_over(bounds);
 
@@ -1177,20 +1216,14 @@ where
         let content_layout = viewport_layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
 
-        let scrollbars = Scrollbars::new(
-            state,
-            self.direction,
-            bounds,
-            viewport_bounds,
-            content_bounds,
-        );
+        let scrollbars = Scrollbars::new(state, direction, bounds, viewport_bounds, content_bounds);
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) = scrollbars.is_mouse_over(cursor);
 
         if (mouse_over_x_scrollbar || mouse_over_y_scrollbar) || state.scrollers_grabbed() {
             mouse::Interaction::None
         } else {
-            let translation = state.translation(self.direction, viewport_bounds, content_bounds);
+            let translation = state.translation(direction, viewport_bounds, content_bounds);
 
             let cursor = match cursor_over_scrollable {
                 Some(cursor_position) if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) => {
@@ -1228,11 +1261,9 @@ where
         let content_bounds = content_layout.bounds();
         let visible_bounds = bounds.intersection(viewport).unwrap_or(*viewport);
 
-        let offset = tree.state.downcast_ref::<State>().translation(
-            self.direction,
-            viewport_bounds,
-            content_bounds,
-        );
+        let state = tree.state.downcast_ref::<State>();
+        let direction = state.direction.unwrap_or(self.direction);
+        let offset = state.translation(direction, viewport_bounds, content_bounds);
 
         self.content.as_widget_mut().overlay(
             &mut tree.children[0],
@@ -1418,6 +1449,7 @@ struct State {
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
     last_scrolled: Option<Instant>,
+    direction: Option<Direction>,
 }
 
 impl Default for State {
@@ -1431,6 +1463,7 @@ impl Default for State {
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
             last_scrolled: None,
+            direction: None,
         }
     }
 }
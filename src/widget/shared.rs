@@ -0,0 +1,140 @@
+// This file is part of `iced_experimental` crate. For the terms of use, please see the file
+// called LICENSE-BSD-3-Clause at the top level of the `iced_experimental` crate.
+
+//! Helpers shared by the widgets of the crate.
+
+use crate::core::{
+    Border, Color, Event, Point, Rectangle, Shadow, Shell, Size, Vector, mouse, renderer, touch,
+    window,
+};
+use crate::widget::Axis;
+use crate::widget::cell::style;
+
+/// The distance the pointer moves while pressed, before a drag starts.
+pub(crate) const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The offset of an overlay of the `size` placed below the `anchor`, or above
+/// if there is no space below, starting at `x` while staying within the
+/// `bounds` of the window.
+pub(crate) fn overlay_offset(anchor: Rectangle, x: f32, size: Size, bounds: Size) -> Vector {
+    let y = if anchor.y + anchor.height + size.height <= bounds.height {
+        anchor.y + anchor.height
+    } else {
+        (anchor.y - size.height).max(0.0)
+    };
+    let x = x.min(bounds.width - size.width).max(0.0);
+    Vector::new(x, y)
+}
+
+/// Draws an ellipsis of three round dots of the `dot` size, separated by the
+/// `gap`, centred on the `centre`.
+pub(crate) fn draw_ellipsis<Renderer>(
+    renderer: &mut Renderer,
+    centre: Point,
+    dot: f32,
+    gap: f32,
+    color: Color,
+) where
+    Renderer: renderer::Renderer,
+{
+    let width = 3.0 * dot + 2.0 * gap;
+    for index in 0..3 {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: centre.x - width / 2.0 + index as f32 * (dot + gap),
+                    y: centre.y - dot / 2.0,
+                    width: dot,
+                    height: dot,
+                },
+                border: Border {
+                    radius: (dot / 2.0).into(),
+                    ..Border::default()
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            color,
+        );
+    }
+}
+
+/// A pointer action on a draggable divider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DividerAction {
+    /// The pointer is pressed, possibly starting a drag.
+    Press,
+    /// The pointer moves, possibly dragging.
+    Move,
+    /// The pointer is released or lost, ending any drag.
+    Release,
+}
+
+/// The [`DividerAction`] of the `event`, if any.
+pub(crate) fn divider_action(event: &Event) -> Option<DividerAction> {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => Some(DividerAction::Press),
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. }) => Some(DividerAction::Move),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => Some(DividerAction::Release),
+        _ => None,
+    }
+}
+
+/// Records the `hovered` divider, requesting a redraw when it changes, except
+/// while already redrawing.
+pub(crate) fn hover_divider<T, Message>(
+    current: &mut T,
+    hovered: T,
+    event: &Event,
+    shell: &mut Shell<'_, Message>,
+) where
+    T: PartialEq,
+{
+    if hovered != *current {
+        *current = hovered;
+        if !matches!(event, Event::Window(window::Event::RedrawRequested(_))) {
+            shell.request_redraw();
+        }
+    }
+}
+
+/// The mouse interaction over a divider between panes placed along the `axis`.
+pub(crate) fn divider_interaction(axis: Axis) -> mouse::Interaction {
+    match axis {
+        Axis::Horizontal => mouse::Interaction::ResizingHorizontally,
+        Axis::Vertical => mouse::Interaction::ResizingVertically,
+    }
+}
+
+/// Draws a divider within the `bounds`, in the style for its status.
+pub(crate) fn draw_divider<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    is_hovered: bool,
+    style: impl Fn(style::Status) -> style::Style,
+) where
+    Renderer: renderer::Renderer,
+{
+    let status = if is_hovered {
+        style::Status::Hovered
+    } else {
+        style::Status::Active
+    };
+    let divider = style(status);
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                radius: divider.border.radius,
+                ..Border::default()
+            },
+            shadow: Shadow::default(),
+            snap: false,
+        },
+        divider.background,
+    );
+}
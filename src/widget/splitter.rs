@@ -0,0 +1,594 @@
+// This file is part of `iced_experimental` crate. For the terms of use, please see the file
+// called LICENSE-BSD-3-Clause at the top level of the `iced_experimental` crate.
+
+//! A container of two panes separated by a draggable divider.
+//!
+//! The divider is styled by the [`Cell`] style catalog, using the ready-made
+//! [`Styling::Divider`] class by default. Dragging the divider produces the
+//! `on_resize` message with the new ratio of the first pane, while a double
+//! click on the divider collapses the first pane, or restores it again.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub struct State { ratio: f32 }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::text;
+//! use iced_experimental_rizzen_yazston::widget::{Axis, splitter};
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     Resized(f32),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     splitter(text("Left pane"), text("Right pane"), state.ratio)
+//!         .axis(Axis::Horizontal)
+//!         .min_size(100.0)
+//!         .on_resize(Message::Resized)
+//!         .into()
+//! }
+//! ```
+
+#[doc(inline)]
+#[allow(unused_imports)]
+use crate::widget::Cell;
+
+use crate::core::{
+    Clipboard,
+    Element,
+    Event,
+    Length,
+    Rectangle,
+    Shell,
+    Size,
+    Vector,
+    layout::{Layout, Limits, Node},
+    mouse,
+    overlay,
+    renderer,
+    widget::{Operation, Tree, Widget, tree}, // operate = iced::runtime::widget
+};
+use crate::widget::Axis;
+use crate::widget::cell::style::{self, Catalog, Styling};
+use crate::widget::shared::{self, DividerAction};
+
+/// Helper function for creating [`Splitter`] instance.
+pub fn splitter<'a, Message, Theme, Renderer>(
+    first: impl Into<Element<'a, Message, Theme, Renderer>>,
+    second: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ratio: f32,
+) -> Splitter<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + 'a,
+    Theme: Catalog + 'a,
+    Theme::Class<'a>: From<Styling>,
+    Message: Clone + 'a,
+{
+    Splitter::new(first, second, ratio)
+}
+
+/// A container of two panes separated by a draggable divider.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub struct State { ratio: f32 }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::text;
+/// use iced_experimental_rizzen_yazston::widget::{Axis, splitter};
+///
+/// #[derive(Clone)]
+/// enum Message {
+///     Resized(f32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     splitter(text("Top pane"), text("Bottom pane"), state.ratio)
+///         .axis(Axis::Vertical)
+///         .on_resize(Message::Resized)
+///         .into()
+/// }
+/// ```
+pub struct Splitter<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    first: Element<'a, Message, Theme, Renderer>,
+    second: Element<'a, Message, Theme, Renderer>,
+    ratio: f32,
+    axis: Axis,
+    width: Length,
+    height: Length,
+    divider_width: f32,
+    min_size: f32,
+    max_size: f32,
+    #[allow(clippy::type_complexity)]
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Splitter<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + 'a,
+    Theme: Catalog + 'a,
+    Message: Clone + 'a,
+{
+    /// Creates a [`Splitter`] of the two panes.
+    ///
+    /// Parameter `ratio`: The fraction of the available space given to the
+    /// `first` pane, where `0.0` is a collapsed first pane.
+    pub fn new(
+        first: impl Into<Element<'a, Message, Theme, Renderer>>,
+        second: impl Into<Element<'a, Message, Theme, Renderer>>,
+        ratio: f32,
+    ) -> Self
+    where
+        Theme::Class<'a>: From<Styling>,
+    {
+        Self {
+            first: first.into(),
+            second: second.into(),
+            ratio: ratio.clamp(0.0, 1.0),
+            axis: Axis::Horizontal,
+            width: Length::Fill,
+            height: Length::Fill,
+            divider_width: 5.0,
+            min_size: 0.0,
+            max_size: f32::INFINITY,
+            on_resize: None,
+            class: Styling::Divider(false).into(),
+        }
+    }
+
+    /// Sets the [`Axis`] along which the panes are placed.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets the width of the [`Splitter`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Splitter`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the width of the divider between the panes.
+    pub fn divider_width(mut self, width: f32) -> Self {
+        self.divider_width = width.max(1.0);
+        self
+    }
+
+    /// Sets the minimum size of each pane along the [`Axis`], except when the
+    /// first pane is collapsed.
+    pub fn min_size(mut self, size: f32) -> Self {
+        self.min_size = size.max(0.0);
+        self
+    }
+
+    /// Sets the maximum size of each pane along the [`Axis`].
+    pub fn max_size(mut self, size: f32) -> Self {
+        self.max_size = size.max(0.0);
+        self
+    }
+
+    /// Sets the message that will be produced when the divider is dragged or
+    /// double clicked, with the new ratio of the first pane. Setting this will
+    /// enable the dragging of the divider.
+    pub fn on_resize(mut self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the style of the divider of the [`Splitter`].
+    pub fn style(mut self, style: impl Fn(&Theme, style::Status) -> style::Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<style::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as style::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the divider of the [`Splitter`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Splitter<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The size of the first pane for the given ratio within the available
+    /// space, honouring the minimum and maximum pane sizes.
+    fn first_size(&self, ratio: f32, available: f32) -> f32 {
+        if ratio <= 0.0 {
+            return 0.0;
+        }
+        let min = self.min_size.max(available - self.max_size);
+        let max = self.max_size.min(available - self.min_size);
+        if min > max {
+            (available / 2.0).max(0.0)
+        } else {
+            (ratio * available).clamp(min, max)
+        }
+    }
+
+    /// The bounds of the divider within the layout of the [`Splitter`].
+    fn divider_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        let bounds = layout.bounds();
+        let first = layout.children().next().unwrap().bounds();
+        match self.axis {
+            Axis::Horizontal => Rectangle {
+                x: first.x + first.width,
+                width: self.divider_width,
+                ..bounds
+            },
+            Axis::Vertical => Rectangle {
+                y: first.y + first.height,
+                height: self.divider_width,
+                ..bounds
+            },
+        }
+    }
+}
+
+/// The state of the divider.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_dragging: bool,
+    is_hovered: bool,
+    last_click: Option<mouse::Click>,
+    restore_ratio: Option<f32>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Splitter<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: Catalog,
+    Message: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.first), Tree::new(&self.second)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[self.first.as_widget(), self.second.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+        let available = (self.axis.main(size) - self.divider_width).max(0.0);
+        let cross = self.axis.cross(size);
+        let first = self.first_size(self.ratio, available);
+        let second = available - first;
+        let first_node = self.first.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, self.axis.size(first, cross)),
+        );
+        let second_node = self
+            .second
+            .as_widget()
+            .layout(
+                &mut tree.children[1],
+                renderer,
+                &Limits::new(Size::ZERO, self.axis.size(second, cross)),
+            )
+            .translate(self.axis.offset(first + self.divider_width));
+
+        // The first pane node takes the full pane size, so the divider follows it.
+        let first_node = Node::with_children(self.axis.size(first, cross), vec![first_node]);
+        Node::with_children(size, vec![first_node, second_node])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            let mut children = layout.children();
+            let first = children.next().unwrap().children().next().unwrap();
+            let second = children.next().unwrap();
+            self.first
+                .as_widget()
+                .operate(&mut tree.children[0], first, renderer, operation);
+            self.second
+                .as_widget()
+                .operate(&mut tree.children[1], second, renderer, operation);
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let divider = self.divider_bounds(layout);
+        let state = tree.state.downcast_mut::<State>();
+
+        // The divider is above the panes while dragging.
+        if !state.is_dragging {
+            let mut children = layout.children();
+            let first = children.next().unwrap().children().next().unwrap();
+            let second = children.next().unwrap();
+            self.first.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                first,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+            self.second.as_widget_mut().update(
+                &mut tree.children[1],
+                event,
+                second,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+        let Some(on_resize) = &self.on_resize else {
+            return;
+        };
+        let bounds = layout.bounds();
+        let available = (self.axis.main(bounds.size()) - self.divider_width).max(0.0);
+
+        match shared::divider_action(event) {
+            Some(DividerAction::Press) => {
+                if shell.is_event_captured() {
+                    return;
+                }
+                if let Some(position) = cursor.position_over(divider) {
+                    let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                    state.last_click = Some(click);
+                    if click.kind() == mouse::click::Kind::Double {
+                        state.is_dragging = false;
+                        if self.ratio > 0.0 {
+                            state.restore_ratio = Some(self.ratio);
+                            shell.publish(on_resize(0.0));
+                        } else {
+                            shell.publish(on_resize(state.restore_ratio.unwrap_or(0.5)));
+                        }
+                    } else {
+                        state.is_dragging = true;
+                    }
+                    shell.capture_event();
+                }
+            }
+            Some(DividerAction::Move) => {
+                if state.is_dragging
+                    && let Some(position) = cursor.position()
+                    && available > 0.0
+                {
+                    let start = self.axis.main_point(bounds.position());
+                    let main = self.axis.main_point(position) - start - self.divider_width / 2.0;
+                    let first = self.first_size(main.max(f32::EPSILON) / available, available);
+                    shell.publish(on_resize(first / available));
+                    shell.capture_event();
+                }
+            }
+            Some(DividerAction::Release) if state.is_dragging => {
+                state.is_dragging = false;
+                shell.capture_event();
+            }
+            _ => {}
+        }
+
+        let is_hovered = state.is_dragging || cursor.is_over(divider);
+        shared::hover_divider(&mut state.is_hovered, is_hovered, event, shell);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if self.on_resize.is_some()
+            && (state.is_dragging || cursor.is_over(self.divider_bounds(layout)))
+        {
+            return shared::divider_interaction(self.axis);
+        }
+        let mut children = layout.children();
+        let first = children.next().unwrap().children().next().unwrap();
+        let second = children.next().unwrap();
+        self.first
+            .as_widget()
+            .mouse_interaction(&tree.children[0], first, cursor, viewport, renderer)
+            .max(self.second.as_widget().mouse_interaction(
+                &tree.children[1],
+                second,
+                cursor,
+                viewport,
+                renderer,
+            ))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(clipped_viewport) = layout.bounds().intersection(viewport) else {
+            return;
+        };
+        let mut children = layout.children();
+        let first_pane = children.next().unwrap();
+        let second = children.next().unwrap();
+        if first_pane.bounds().width > 0.0 && first_pane.bounds().height > 0.0 {
+            renderer.with_layer(first_pane.bounds(), |renderer| {
+                self.first.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    first_pane.children().next().unwrap(),
+                    cursor,
+                    &clipped_viewport,
+                );
+            });
+        }
+        renderer.with_layer(second.bounds(), |renderer| {
+            self.second.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                second,
+                cursor,
+                &clipped_viewport,
+            );
+        });
+
+        let state = tree.state.downcast_ref::<State>();
+        shared::draw_divider(
+            renderer,
+            self.divider_bounds(layout),
+            state.is_hovered,
+            |status| theme.style(&self.class, status),
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = layout.children();
+        let first = children.next().unwrap().children().next().unwrap();
+        let second = children.next().unwrap();
+        let mut trees = tree.children.iter_mut();
+        let first = self.first.as_widget_mut().overlay(
+            trees.next().unwrap(),
+            first,
+            renderer,
+            viewport,
+            translation,
+        );
+        let second = self.second.as_widget_mut().overlay(
+            trees.next().unwrap(),
+            second,
+            renderer,
+            viewport,
+            translation,
+        );
+        let overlays: Vec<_> = first.into_iter().chain(second).collect();
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Splitter<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(
+        splitter: Splitter<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(splitter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Theme;
+    use crate::core::Point;
+    use iced_widget::Space;
+
+    /// A [`Splitter`] of two empty panes.
+    fn splitter(ratio: f32) -> Splitter<'static, (), Theme, ()> {
+        Splitter::new(Space::new(0.0, 0.0), Space::new(0.0, 0.0), ratio)
+    }
+
+    #[test]
+    fn ratio_is_clamped_to_a_fraction() {
+        assert_eq!(splitter(-0.5).ratio, 0.0);
+        assert_eq!(splitter(1.5).ratio, 1.0);
+    }
+
+    #[test]
+    fn first_size_honours_the_pane_sizes() {
+        let splitter = splitter(0.5).min_size(30.0);
+        assert_eq!(splitter.first_size(0.5, 100.0), 50.0);
+        assert_eq!(splitter.first_size(0.1, 100.0), 30.0);
+        assert_eq!(splitter.first_size(0.9, 100.0), 70.0);
+        assert_eq!(splitter.first_size(0.0, 100.0), 0.0);
+        let splitter = splitter.max_size(60.0);
+        assert_eq!(splitter.first_size(0.2, 100.0), 40.0);
+        assert_eq!(splitter.first_size(0.8, 100.0), 60.0);
+    }
+
+    #[test]
+    fn first_size_splits_evenly_when_the_pane_sizes_conflict() {
+        let splitter = splitter(0.2).min_size(60.0);
+        assert_eq!(splitter.first_size(0.2, 100.0), 50.0);
+        let splitter = splitter.min_size(0.0).max_size(40.0);
+        assert_eq!(splitter.first_size(0.9, 100.0), 50.0);
+    }
+
+    #[test]
+    fn divider_follows_the_first_pane() {
+        let splitter = splitter(0.5).width(105.0).height(20.0);
+        let mut tree = Tree::new(&splitter as &dyn Widget<(), Theme, ()>);
+        let limits = Limits::new(Size::ZERO, Size::new(200.0, 100.0));
+        let node = splitter.layout(&mut tree, &(), &limits);
+        let divider = splitter.divider_bounds(Layout::new(&node));
+        assert_eq!(
+            divider,
+            Rectangle::new(Point::new(50.0, 0.0), Size::new(5.0, 20.0))
+        );
+        assert_eq!(node.children()[1].bounds().x, 55.0);
+    }
+}